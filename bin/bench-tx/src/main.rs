@@ -3,15 +3,22 @@ use std::{
     fs::{read_to_string, write, File},
     io::Write,
     path::Path,
+    time::Instant,
 };
 
-use miden_lib::{note::create_p2id_note, transaction::TransactionKernel};
+use miden_lib::{
+    account::{auth::RpoFalcon512, blob::BlobStorage, wallets::BasicWallet},
+    note::create_p2id_note,
+    transaction::{ExecutionContextCache, TransactionKernel},
+};
 use miden_objects::{
-    account::{AccountId, AccountStorageMode, AccountType},
+    account::{Account, AccountBuilder, AccountId, AccountStorageMode, AccountType},
     asset::{Asset, FungibleAsset},
     crypto::rand::RpoRandomCoin,
     note::NoteType,
-    transaction::{TransactionArgs, TransactionMeasurements, TransactionScript},
+    transaction::{
+        InputNotes, TransactionArgs, TransactionInputs, TransactionMeasurements, TransactionScript,
+    },
     Felt,
 };
 use miden_tx::{testing::TransactionContextBuilder, TransactionExecutor};
@@ -20,8 +27,8 @@ use vm_processor::ONE;
 mod utils;
 use utils::{
     get_account_with_basic_authenticated_wallet, get_new_pk_and_authenticator,
-    write_bench_results_to_json, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_SENDER,
-    DEFAULT_AUTH_SCRIPT,
+    write_bench_results_to_json, InputPreparationMeasurements, MeasurementsPrinter,
+    ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_SENDER, DEFAULT_AUTH_SCRIPT,
 };
 pub enum Benchmark {
     Simple,
@@ -45,13 +52,16 @@ fn main() -> Result<(), String> {
 
     // run all available benchmarks
     let benchmark_results = vec![
-        (Benchmark::Simple, benchmark_default_tx()?.into()),
-        (Benchmark::P2ID, benchmark_p2id()?.into()),
+        (Benchmark::Simple, to_json(MeasurementsPrinter::from(benchmark_default_tx()?))?),
+        (Benchmark::P2ID, to_json(MeasurementsPrinter::from(benchmark_p2id()?))?),
     ];
 
     // store benchmark results in the JSON file
     write_bench_results_to_json(path, benchmark_results)?;
 
+    // input preparation timings depend on the machine, so they are printed instead of stored
+    println!("{}", benchmark_input_preparation()?);
+
     Ok(())
 }
 
@@ -140,3 +150,70 @@ pub fn benchmark_p2id() -> Result<TransactionMeasurements, String> {
 
     Ok(executed_transaction.into())
 }
+
+/// Prepares the inputs of a sequence of transactions against an account storing a large blob,
+/// once without and once with an [ExecutionContextCache].
+///
+/// Every transaction of the sequence adds an asset to the account vault, while the account code
+/// and the storage map holding the blob stay unchanged.
+pub fn benchmark_input_preparation() -> Result<InputPreparationMeasurements, String> {
+    const NUM_TRANSACTIONS: u64 = 20;
+
+    let (pub_key, _) = get_new_pk_and_authenticator();
+    let blob = (0..BlobStorage::MAX_BLOB_SIZE / 16).map(|byte| byte as u8).collect();
+    let account = AccountBuilder::new([11; 32])
+        .with_component(BasicWallet)
+        .with_component(RpoFalcon512::new(pub_key))
+        .with_component(BlobStorage::new(blob).map_err(|e| e.to_string())?)
+        .build_existing()
+        .map_err(|e| e.to_string())?;
+
+    let tx_context = TransactionContextBuilder::new(account.clone()).build();
+    let block_header = *tx_context.tx_inputs().block_header();
+    let block_chain = tx_context.tx_inputs().block_chain();
+
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let tx_inputs = (1..=NUM_TRANSACTIONS)
+        .map(|amount| {
+            let (id, mut vault, storage, code, nonce) = account.clone().into_parts();
+            vault.add_asset(FungibleAsset::new(faucet_id, amount).unwrap().into()).unwrap();
+            let account = Account::from_parts(id, vault, storage, code, nonce);
+
+            TransactionInputs::new(
+                account,
+                None,
+                block_header,
+                block_chain.clone(),
+                InputNotes::default(),
+            )
+            .map_err(|e| e.to_string())
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let tx_args = TransactionArgs::default();
+
+    let start = Instant::now();
+    for tx_inputs in tx_inputs.iter() {
+        TransactionKernel::prepare_inputs(tx_inputs, &tx_args, None);
+    }
+    let without_cache = start.elapsed();
+
+    let mut cache = ExecutionContextCache::new();
+    let start = Instant::now();
+    for tx_inputs in tx_inputs.iter() {
+        TransactionKernel::prepare_inputs_with_cache(tx_inputs, &tx_args, None, &mut cache);
+    }
+    let with_cache = start.elapsed();
+
+    Ok(InputPreparationMeasurements::new(
+        NUM_TRANSACTIONS as usize,
+        without_cache,
+        with_cache,
+    ))
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn to_json(printer: impl serde::Serialize) -> Result<serde_json::Value, String> {
+    serde_json::to_value(printer).map_err(|e| e.to_string())
+}
@@ -1,6 +1,6 @@
 extern crate alloc;
 pub use alloc::{collections::BTreeMap, string::String};
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 
 use miden_lib::account::{auth::RpoFalcon512, wallets::BasicWallet};
 use miden_objects::{
@@ -14,7 +14,7 @@ use rand_chacha::{rand_core::SeedableRng, ChaCha20Rng};
 use serde::Serialize;
 use serde_json::{from_str, to_string_pretty, Value};
 
-use super::{read_to_string, write, Benchmark, Path};
+use super::{fmt, read_to_string, write, Benchmark, Path};
 
 // CONSTANTS
 // ================================================================================================
@@ -58,6 +58,39 @@ impl From<TransactionMeasurements> for MeasurementsPrinter {
     }
 }
 
+// INPUT PREPARATION MEASUREMENTS
+// ================================================================================================
+
+#[derive(Debug, Clone)]
+pub struct InputPreparationMeasurements {
+    num_transactions: usize,
+    without_cache: Duration,
+    with_cache: Duration,
+}
+
+impl InputPreparationMeasurements {
+    pub fn new(num_transactions: usize, without_cache: Duration, with_cache: Duration) -> Self {
+        Self {
+            num_transactions,
+            without_cache,
+            with_cache,
+        }
+    }
+}
+
+impl fmt::Display for InputPreparationMeasurements {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Input preparation of {} transactions:", self.num_transactions)?;
+        writeln!(f, "- without cache: {} ms", self.without_cache.as_millis())?;
+        writeln!(f, "- with cache: {} ms", self.with_cache.as_millis())?;
+        write!(
+            f,
+            "- speedup: {:.2}x",
+            self.without_cache.as_secs_f64() / self.with_cache.as_secs_f64()
+        )
+    }
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
@@ -93,16 +126,14 @@ pub fn get_new_pk_and_authenticator() -> (PublicKey, Arc<dyn TransactionAuthenti
 
 pub fn write_bench_results_to_json(
     path: &Path,
-    tx_benchmarks: Vec<(Benchmark, MeasurementsPrinter)>,
+    tx_benchmarks: Vec<(Benchmark, Value)>,
 ) -> Result<(), String> {
     // convert benchmark file internals to the JSON Value
     let benchmark_file = read_to_string(path).map_err(|e| e.to_string())?;
     let mut benchmark_json: Value = from_str(&benchmark_file).map_err(|e| e.to_string())?;
 
     // fill benchmarks JSON with results of each benchmark
-    for (bench_type, tx_progress) in tx_benchmarks {
-        let tx_benchmark_json = serde_json::to_value(tx_progress).map_err(|e| e.to_string())?;
-
+    for (bench_type, tx_benchmark_json) in tx_benchmarks {
         benchmark_json[bench_type.to_string()] = tx_benchmark_json;
     }
 
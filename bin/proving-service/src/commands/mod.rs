@@ -45,6 +45,9 @@ pub struct ProxyConfig {
     pub prometheus_host: String,
     /// Prometheus metrics port.
     pub prometheus_port: u16,
+    /// Telemetry configuration.
+    #[serde(default)]
+    pub telemetry: TelemetryConfig,
 }
 
 impl Default for ProxyConfig {
@@ -61,6 +64,7 @@ impl Default for ProxyConfig {
             health_check_interval_secs: 1,
             prometheus_host: "127.0.0.1".into(),
             prometheus_port: 6192,
+            telemetry: TelemetryConfig::default(),
         }
     }
 }
@@ -81,6 +85,87 @@ impl ProxyConfig {
     }
 }
 
+/// Telemetry configuration of the proving service.
+///
+/// It is stored in the `[telemetry]` section of the configuration file and is used by both the
+/// proxy and the workers. If the section or any of its values is missing, the default values are
+/// used.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TelemetryConfig {
+    /// Whether traces are exported via OTLP.
+    pub otlp_exporter_enabled: bool,
+    /// Endpoint of the OTLP collector.
+    ///
+    /// If not set, the endpoint defined by the `OTEL_EXPORTER_OTLP_ENDPOINT` environment variable
+    /// is used, falling back to `http://localhost:4317`.
+    pub otlp_endpoint: Option<String>,
+    /// Ratio of traces to sample, between `0.0` (no traces) and `1.0` (all traces).
+    pub trace_sampling_ratio: f64,
+    /// Whether the proxy metrics are exported via OTLP.
+    pub metrics_exporter_enabled: bool,
+    /// Interval in seconds at which metrics are exported. Must be greater than zero.
+    pub metrics_export_interval_secs: u64,
+}
+
+impl Default for TelemetryConfig {
+    fn default() -> Self {
+        Self {
+            otlp_exporter_enabled: true,
+            otlp_endpoint: None,
+            trace_sampling_ratio: 1.0,
+            metrics_exporter_enabled: false,
+            metrics_export_interval_secs: 60,
+        }
+    }
+}
+
+impl TelemetryConfig {
+    /// Loads the telemetry configuration from the config file in the current directory.
+    ///
+    /// Unlike [ProxyConfig::load_config_from_file], this function does not fail if the config file
+    /// does not exist, in which case the default telemetry configuration is returned.
+    pub(crate) fn load_config_from_file() -> Result<TelemetryConfig, String> {
+        let mut current_dir = std::env::current_dir().map_err(|err| err.to_string())?;
+        current_dir.push(PROVING_SERVICE_CONFIG_FILE_NAME);
+        let config_path = current_dir.as_path();
+
+        Self::extract(Figment::from(Toml::file(config_path)))
+            .map_err(|err| format!("Failed to load {} config file: {err}", config_path.display()))
+    }
+
+    /// Extracts the telemetry configuration from the `[telemetry]` section of the provided
+    /// configuration and validates it.
+    fn extract(figment: Figment) -> Result<TelemetryConfig, String> {
+        let config: TelemetryConfig =
+            figment.focus("telemetry").extract().map_err(|err| err.to_string())?;
+        config.validate()?;
+
+        Ok(config)
+    }
+
+    /// Checks that the values of the telemetry configuration are valid.
+    ///
+    /// Returns an error if:
+    /// - the trace sampling ratio is not between `0.0` and `1.0`.
+    /// - the metrics export interval is zero.
+    pub(crate) fn validate(&self) -> Result<(), String> {
+        if !(0.0..=1.0).contains(&self.trace_sampling_ratio) {
+            return Err(format!(
+                "Invalid trace sampling ratio {}: must be between 0.0 and 1.0",
+                self.trace_sampling_ratio
+            ));
+        }
+
+        // a zero interval would be silently replaced by the default interval of the exporter
+        if self.metrics_export_interval_secs == 0 {
+            return Err("Invalid metrics export interval 0: must be greater than zero".to_string());
+        }
+
+        Ok(())
+    }
+}
+
 /// Root CLI struct
 #[derive(Parser, Debug)]
 #[clap(
@@ -119,6 +204,17 @@ pub enum Command {
 
 /// CLI entry point
 impl Cli {
+    /// Returns the telemetry configuration used when executing the command.
+    ///
+    /// The `init` command creates the config file and thus uses the default telemetry
+    /// configuration, while all other commands load it from the config file.
+    pub fn telemetry_config(&self) -> Result<TelemetryConfig, String> {
+        match &self.action {
+            Command::Init(_) => Ok(TelemetryConfig::default()),
+            _ => TelemetryConfig::load_config_from_file(),
+        }
+    }
+
     #[instrument(target = MIDEN_PROVING_SERVICE, name = "cli:execute", skip_all, ret(level = "info"), err)]
     pub async fn execute(&self) -> Result<(), String> {
         match &self.action {
@@ -140,3 +236,98 @@ impl Cli {
         }
     }
 }
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use clap::{error::ErrorKind, Parser};
+    use figment::{
+        providers::{Format, Toml},
+        Figment,
+    };
+
+    use super::{Cli, TelemetryConfig};
+
+    fn extract(toml: &str) -> Result<TelemetryConfig, String> {
+        TelemetryConfig::extract(Figment::from(Toml::string(toml)))
+    }
+
+    #[test]
+    fn telemetry_config_defaults() {
+        let config = TelemetryConfig::default();
+        assert!(config.otlp_exporter_enabled);
+        assert_eq!(config.otlp_endpoint, None);
+        assert_eq!(config.trace_sampling_ratio, 1.0);
+        assert!(!config.metrics_exporter_enabled);
+        assert_eq!(config.metrics_export_interval_secs, 60);
+        config.validate().unwrap();
+
+        // a missing telemetry section falls back to the default configuration
+        assert_eq!(extract("").unwrap(), TelemetryConfig::default());
+        assert_eq!(extract("host = \"0.0.0.0\"").unwrap(), TelemetryConfig::default());
+    }
+
+    #[test]
+    fn telemetry_config_from_toml() {
+        let config = extract(
+            r#"
+            [telemetry]
+            otlp_exporter_enabled = false
+            otlp_endpoint = "http://collector:4317"
+            trace_sampling_ratio = 0.25
+            metrics_exporter_enabled = true
+            metrics_export_interval_secs = 10
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            config,
+            TelemetryConfig {
+                otlp_exporter_enabled: false,
+                otlp_endpoint: Some("http://collector:4317".into()),
+                trace_sampling_ratio: 0.25,
+                metrics_exporter_enabled: true,
+                metrics_export_interval_secs: 10,
+            }
+        );
+
+        // missing values fall back to their defaults
+        let config = extract("[telemetry]\ntrace_sampling_ratio = 0.5").unwrap();
+        assert_eq!(
+            config,
+            TelemetryConfig {
+                trace_sampling_ratio: 0.5,
+                ..TelemetryConfig::default()
+            }
+        );
+
+        assert!(extract("[telemetry]\ntrace_sampling_ratio = \"all\"").is_err());
+    }
+
+    #[test]
+    fn telemetry_config_validation() {
+        for ratio in [0.0, 0.5, 1.0] {
+            extract(&format!("[telemetry]\ntrace_sampling_ratio = {ratio:?}")).unwrap();
+        }
+        for ratio in [-0.1, 1.5] {
+            let err =
+                extract(&format!("[telemetry]\ntrace_sampling_ratio = {ratio:?}")).unwrap_err();
+            assert!(err.contains("Invalid trace sampling ratio"));
+        }
+
+        let err = extract("[telemetry]\nmetrics_export_interval_secs = 0").unwrap_err();
+        assert!(err.contains("Invalid metrics export interval"));
+    }
+
+    #[test]
+    fn cli_does_not_require_config_file() {
+        let err = Cli::try_parse_from(["miden-proving-service", "--help"]).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::DisplayHelp);
+
+        let cli = Cli::try_parse_from(["miden-proving-service", "init"]).unwrap();
+        assert_eq!(cli.telemetry_config().unwrap(), TelemetryConfig::default());
+    }
+}
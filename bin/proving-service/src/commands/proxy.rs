@@ -10,7 +10,7 @@ use tracing::warn;
 
 use crate::{
     error::TxProverServiceError,
-    proxy::{LoadBalancer, LoadBalancerState},
+    proxy::{metrics::register_otel_metrics, LoadBalancer, LoadBalancerState},
     utils::MIDEN_PROVING_SERVICE,
 };
 
@@ -75,7 +75,8 @@ impl StartProxy {
         http_server_options.h2c = true;
         logic.server_options = Some(http_server_options);
 
-        // Enable Prometheus metrics
+        // Enable Prometheus metrics and export them via OpenTelemetry, if enabled
+        register_otel_metrics();
         let mut prometheus_service_http =
             pingora::services::listening::Service::prometheus_http_service();
         prometheus_service_http.add_tcp(
@@ -5,17 +5,21 @@ mod generated;
 pub mod proxy;
 mod utils;
 use commands::Cli;
-use utils::setup_tracing;
+use utils::{setup_metrics, setup_tracing};
 
 #[tokio::main]
 async fn main() -> Result<(), String> {
     use clap::Parser;
 
-    setup_tracing()?;
-
     // read command-line args
     let cli = Cli::parse();
 
+    // the telemetry configuration is only loaded after parsing the command-line args, such that
+    // a missing or malformed config file does not prevent running `--help` or `init`
+    let telemetry_config = cli.telemetry_config()?;
+    setup_tracing(&telemetry_config)?;
+    setup_metrics(&telemetry_config)?;
+
     // execute cli action
     cli.execute().await
 }
@@ -1,10 +1,13 @@
 use std::sync::LazyLock;
 
+use opentelemetry::{global, metrics::Meter};
 use prometheus::{
     register_histogram, register_int_counter, register_int_counter_vec, register_int_gauge,
     Histogram, IntCounter, IntCounterVec, IntGauge,
 };
 
+use crate::utils::MIDEN_PROVING_SERVICE;
+
 // SAFETY: The `unwrap` calls here are safe because:
 // 1. The metrics being registered (gauges, counters, histograms) use hardcoded names and
 //    descriptions, which are guaranteed not to conflict within the application.
@@ -88,3 +91,68 @@ pub static RATE_LIMIT_VIOLATIONS: LazyLock<IntCounter> = LazyLock::new(|| {
     register_int_counter!("rate_limit_violations", "Number of rate limit violations by clients")
         .unwrap()
 });
+
+// OPENTELEMETRY METRICS
+// ================================================================================================
+
+/// Registers the queue, worker and request metrics with the global OpenTelemetry meter provider.
+///
+/// The metrics are registered as observable instruments which read the current values of the
+/// Prometheus metrics defined in this module whenever the meter provider collects them. If no
+/// meter provider was installed (see [setup_metrics](crate::utils::setup_metrics)), this is a
+/// no-op.
+pub(crate) fn register_otel_metrics() {
+    let meter = global::meter(MIDEN_PROVING_SERVICE);
+
+    register_otel_gauge(&meter, &QUEUE_SIZE, "queue_size", "Number of requests in the queue");
+    register_otel_counter(
+        &meter,
+        &QUEUE_DROP_COUNT,
+        "queue_drop_count",
+        "Number of requests dropped due to a full queue",
+    );
+    register_otel_gauge(&meter, &WORKER_COUNT, "worker_count", "Total number of workers");
+    register_otel_gauge(&meter, &WORKER_BUSY, "worker_busy", "Number of busy workers");
+    register_otel_counter(
+        &meter,
+        &WORKER_UNHEALTHY,
+        "worker_unhealthy",
+        "Number of times that workers were registered as unhealthy",
+    );
+    register_otel_counter(&meter, &REQUEST_COUNT, "request_count", "Number of requests processed");
+    register_otel_counter(
+        &meter,
+        &REQUEST_FAILURE_COUNT,
+        "request_failure_count",
+        "Number of failed requests",
+    );
+    register_otel_counter(&meter, &REQUEST_RETRIES, "request_retries", "Number of request retries");
+}
+
+/// Registers an observable gauge which reports the value of the provided Prometheus gauge.
+fn register_otel_gauge(
+    meter: &Meter,
+    gauge: &'static LazyLock<IntGauge>,
+    name: &'static str,
+    description: &'static str,
+) {
+    meter
+        .i64_observable_gauge(name)
+        .with_description(description)
+        .with_callback(move |observer| observer.observe(gauge.get(), &[]))
+        .build();
+}
+
+/// Registers an observable counter which reports the value of the provided Prometheus counter.
+fn register_otel_counter(
+    meter: &Meter,
+    counter: &'static LazyLock<IntCounter>,
+    name: &'static str,
+    description: &'static str,
+) {
+    meter
+        .u64_observable_counter(name)
+        .with_description(description)
+        .with_callback(move |observer| observer.observe(counter.get(), &[]))
+        .build();
+}
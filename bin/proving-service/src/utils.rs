@@ -1,7 +1,9 @@
 use std::time::Duration;
 
-use opentelemetry::{trace::TracerProvider as _, KeyValue};
+use opentelemetry::{global, trace::TracerProvider as _, KeyValue};
+use opentelemetry_otlp::WithExportConfig;
 use opentelemetry_sdk::{
+    metrics::{PeriodicReader, SdkMeterProvider},
     runtime,
     trace::{RandomIdGenerator, Sampler, TracerProvider},
     Resource,
@@ -16,7 +18,9 @@ use tonic::transport::Channel;
 use tonic_health::pb::health_client::HealthClient;
 use tracing_subscriber::{layer::SubscriberExt, Registry};
 
-use crate::{error::TxProverServiceError, proxy::metrics::QUEUE_DROP_COUNT};
+use crate::{
+    commands::TelemetryConfig, error::TxProverServiceError, proxy::metrics::QUEUE_DROP_COUNT,
+};
 
 pub const MIDEN_PROVING_SERVICE: &str = "miden-proving-service";
 
@@ -30,29 +34,31 @@ pub const PROVING_SERVICE_CONFIG_FILE_NAME: &str = "miden-proving-service.toml";
 ///
 /// This function sets up a tracing pipeline that includes:
 ///
-/// - An OpenTelemetry (OTLP) exporter, which sends span data to an OTLP endpoint using gRPC.
-/// - A [TracerProvider] configured with a [Sampler::ParentBased] sampler at a `1.0` sampling ratio,
-///   ensuring that all traces are recorded.
+/// - An OpenTelemetry (OTLP) exporter, which sends span data to the OTLP endpoint defined in the
+///   [TelemetryConfig] using gRPC. The exporter is only set up if it is enabled in the config.
+/// - A [TracerProvider] configured with a [Sampler::ParentBased] sampler at the sampling ratio
+///   defined in the [TelemetryConfig].
 /// - A resource containing the service name and version extracted from the crate's metadata.
 /// - A `tracing` subscriber that integrates the configured [TracerProvider] with the Rust `tracing`
 ///   ecosystem, applying filters from the environment and enabling formatted console logs.
 ///
 /// **Process:**
-/// 1. **OTLP Exporter**:   Creates an OTLP span exporter that sends trace data to a collector
-///    endpoint. If it fails to create the exporter, returns an error describing the failure.
+/// 1. **OTLP Exporter**:   If enabled, creates an OTLP span exporter that sends trace data to a
+///    collector endpoint. If it fails to create the exporter, returns an error describing the
+///    failure.
 ///
 /// 2. **Resource Setup**:   Creates a [Resource] containing service metadata (name and version),
 ///    which is attached to all emitted telemetry data to identify the originating service.
 ///
 /// 3. **TracerProvider and Sampler**:   Builds a [TracerProvider] using a [Sampler::ParentBased]
-///    sampler layered over a [Sampler::TraceIdRatioBased] sampler set to `1.0`, ensuring all traces
-///    are recorded. A random ID generator is used to produce trace and span IDs. The tracer is
-///    retrieved from this provider, which can then be used by the OpenTelemetry layer of `tracing`.
+///    sampler layered over a [Sampler::TraceIdRatioBased] sampler set to the configured ratio. A
+///    random ID generator is used to produce trace and span IDs. The tracer is retrieved from this
+///    provider, which can then be used by the OpenTelemetry layer of `tracing`.
 ///
 /// 4. **Telemetry Integration with tracing**:   Creates a telemetry layer from
 ///    `tracing_opentelemetry` and combines it with a `Registry` subscriber and a formatting layer.
 ///    This results in a subscriber stack that:
-///    - Sends telemetry to the OTLP exporter.
+///    - Sends telemetry to the OTLP exporter, if enabled.
 ///    - Filters logs/spans based on environment variables.
 ///    - Pretty-prints formatted logs to stdout.
 ///
@@ -63,30 +69,31 @@ pub const PROVING_SERVICE_CONFIG_FILE_NAME: &str = "miden-proving-service.toml";
 /// - `Ok(())` if the global subscriber is successfully set up.
 /// - `Err(String)` describing the failure if any step (creating the exporter or setting the
 ///   subscriber) fails.
-pub(crate) fn setup_tracing() -> Result<(), String> {
-    let exporter = opentelemetry_otlp::SpanExporter::builder()
-        .with_tonic()
-        .build()
-        .map_err(|e| format!("Failed to create OTLP exporter: {:?}", e))?;
-
-    let resource = Resource::from_schema_url(
-        [
-            KeyValue::new(SERVICE_NAME, env!("CARGO_PKG_NAME")),
-            KeyValue::new(SERVICE_VERSION, env!("CARGO_PKG_VERSION")),
-        ],
-        SCHEMA_URL,
-    );
-
-    let provider = TracerProvider::builder()
-        .with_sampler(Sampler::ParentBased(Box::new(Sampler::TraceIdRatioBased(1.0))))
-        .with_id_generator(RandomIdGenerator::default())
-        .with_resource(resource)
-        .with_batch_exporter(exporter, runtime::Tokio)
-        .build();
-
-    let tracer = provider.tracer(MIDEN_PROVING_SERVICE);
-
-    let telemetry = tracing_opentelemetry::layer().with_tracer(tracer);
+pub(crate) fn setup_tracing(config: &TelemetryConfig) -> Result<(), String> {
+    let telemetry = if config.otlp_exporter_enabled {
+        let mut exporter_builder = opentelemetry_otlp::SpanExporter::builder().with_tonic();
+        if let Some(endpoint) = &config.otlp_endpoint {
+            exporter_builder = exporter_builder.with_endpoint(endpoint);
+        }
+        let exporter = exporter_builder
+            .build()
+            .map_err(|e| format!("Failed to create OTLP exporter: {:?}", e))?;
+
+        let provider = TracerProvider::builder()
+            .with_sampler(Sampler::ParentBased(Box::new(Sampler::TraceIdRatioBased(
+                config.trace_sampling_ratio,
+            ))))
+            .with_id_generator(RandomIdGenerator::default())
+            .with_resource(service_resource())
+            .with_batch_exporter(exporter, runtime::Tokio)
+            .build();
+
+        let tracer = provider.tracer(MIDEN_PROVING_SERVICE);
+
+        Some(tracing_opentelemetry::layer().with_tracer(tracer))
+    } else {
+        None
+    };
 
     let subscriber = Registry::default()
         .with(telemetry)
@@ -97,6 +104,58 @@ pub(crate) fn setup_tracing() -> Result<(), String> {
         .map_err(|e| format!("Failed to set subscriber: {:?}", e))
 }
 
+/// Initializes the global OpenTelemetry metrics pipeline.
+///
+/// If the metrics exporter is enabled in the [TelemetryConfig], this function sets up an OTLP
+/// metric exporter which sends the metrics to the configured OTLP endpoint using gRPC every
+/// `metrics_export_interval_secs` seconds, and installs it as the global meter provider. Otherwise,
+/// the global meter provider is left as a no-op provider.
+///
+/// The metrics themselves are registered separately, see
+/// [register_otel_metrics](crate::proxy::metrics::register_otel_metrics).
+///
+/// **Returns:**
+/// - `Ok(())` if the metrics pipeline is successfully set up or disabled.
+/// - `Err(String)` describing the failure if the exporter cannot be created.
+pub(crate) fn setup_metrics(config: &TelemetryConfig) -> Result<(), String> {
+    if !config.metrics_exporter_enabled {
+        return Ok(());
+    }
+
+    let mut exporter_builder = opentelemetry_otlp::MetricExporter::builder().with_tonic();
+    if let Some(endpoint) = &config.otlp_endpoint {
+        exporter_builder = exporter_builder.with_endpoint(endpoint);
+    }
+    let exporter = exporter_builder
+        .build()
+        .map_err(|e| format!("Failed to create OTLP metric exporter: {:?}", e))?;
+
+    let reader = PeriodicReader::builder(exporter, runtime::Tokio)
+        .with_interval(Duration::from_secs(config.metrics_export_interval_secs))
+        .build();
+
+    let provider = SdkMeterProvider::builder()
+        .with_reader(reader)
+        .with_resource(service_resource())
+        .build();
+
+    global::set_meter_provider(provider);
+
+    Ok(())
+}
+
+/// Returns a [Resource] containing the service name and version extracted from the crate's
+/// metadata.
+fn service_resource() -> Resource {
+    Resource::from_schema_url(
+        [
+            KeyValue::new(SERVICE_NAME, env!("CARGO_PKG_NAME")),
+            KeyValue::new(SERVICE_VERSION, env!("CARGO_PKG_VERSION")),
+        ],
+        SCHEMA_URL,
+    )
+}
+
 /// Create a 503 response for a full queue
 pub(crate) async fn create_queue_full_response(
     session: &mut Session,
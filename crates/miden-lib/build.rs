@@ -296,9 +296,29 @@ const RPO_FALCON_AUTH_CODE: &str = "
     export.::miden::contracts::auth::basic::auth_tx_rpo_falcon512
 ";
 
+const RPO_FALCON_UPGRADABLE_AUTH_CODE: &str = "
+    export.::miden::contracts::auth::basic::auth_tx_rpo_falcon512
+    export.::miden::contracts::auth::basic::upgrade_code_rpo_falcon512
+";
+
 const BASIC_FUNGIBLE_FAUCET_CODE: &str = "
     export.::miden::contracts::faucets::basic_fungible::distribute
     export.::miden::contracts::faucets::basic_fungible::burn
+    export.::miden::contracts::faucets::basic_fungible::set_paused
+    export.::miden::contracts::faucets::basic_fungible::set_recipient_mint_cap
+    export.::miden::contracts::faucets::basic_fungible::set_max_supply
+";
+
+const BLOB_STORAGE_CODE: &str = "
+    export.::miden::contracts::blob::basic::write_blob
+    export.::miden::contracts::blob::basic::read_chunk
+    export.::miden::contracts::blob::basic::get_blob_info
+    export.::miden::contracts::blob::basic::clear_blob
+";
+
+const PRICE_ORACLE_CODE: &str = "
+    export.::miden::contracts::oracles::price::push_price
+    export.::miden::contracts::oracles::price::get_price
 ";
 
 /// Compiles the default account components into a MASL library and stores the complied files in
@@ -307,7 +327,10 @@ fn compile_account_components(target_dir: &Path, assembler: Assembler) -> Result
     for (component_name, component_code) in [
         ("basic_wallet", BASIC_WALLET_CODE),
         ("rpo_falcon_512", RPO_FALCON_AUTH_CODE),
+        ("rpo_falcon_512_upgradable", RPO_FALCON_UPGRADABLE_AUTH_CODE),
         ("basic_fungible_faucet", BASIC_FUNGIBLE_FAUCET_CODE),
+        ("blob_storage", BLOB_STORAGE_CODE),
+        ("price_oracle", PRICE_ORACLE_CODE),
     ] {
         let component_library = assembler.clone().assemble_library([component_code])?;
         let component_file_path =
@@ -1,9 +1,9 @@
 use miden_objects::{
-    account::{AccountComponent, StorageSlot},
+    account::{AccountComponent, AccountType, StorageSlot},
     crypto::dsa::rpo_falcon512::PublicKey,
 };
 
-use crate::account::components::rpo_falcon_512_library;
+use crate::account::components::{rpo_falcon_512_library, rpo_falcon_512_upgradable_library};
 
 /// An [`AccountComponent`] implementing the RpoFalcon512 signature scheme for authentication of
 /// transactions.
@@ -34,3 +34,42 @@ impl From<RpoFalcon512> for AccountComponent {
         .with_supports_all_types()
     }
 }
+
+/// An [`AccountComponent`] implementing the RpoFalcon512 signature scheme for authentication of
+/// transactions and account code upgrades.
+///
+/// Its exported procedures are:
+/// - `auth_tx_rpo_falcon512`, which can be used to verify a signature provided via the advice stack
+///   to authenticate a transaction.
+/// - `upgrade_code_rpo_falcon512`, which upgrades the account code to the code with the commitment
+///   provided via the operand stack and authenticates the transaction using a signature which
+///   additionally commits to the new code commitment. Only accounts with updatable code can be
+///   upgraded.
+///
+/// This component is an opt-in alternative to [`RpoFalcon512`] for accounts whose owner should be
+/// able to upgrade the account code. Since `auth_tx_rpo_falcon512` commits to the new code
+/// commitment whenever the code was changed in the transaction, the account code can only be
+/// upgraded with a signature of the owner.
+///
+/// This component supports regular accounts with updatable code.
+pub struct RpoFalcon512Upgradable {
+    public_key: PublicKey,
+}
+
+impl RpoFalcon512Upgradable {
+    /// Creates a new [`RpoFalcon512Upgradable`] component with the given `public_key`.
+    pub fn new(public_key: PublicKey) -> Self {
+        Self { public_key }
+    }
+}
+
+impl From<RpoFalcon512Upgradable> for AccountComponent {
+    fn from(falcon: RpoFalcon512Upgradable) -> Self {
+        AccountComponent::new(
+            rpo_falcon_512_upgradable_library(),
+            vec![StorageSlot::Value(falcon.public_key.into())],
+        )
+        .expect("falcon component should satisfy the requirements of a valid account component")
+        .with_supported_type(AccountType::RegularAccountUpdatableCode)
+    }
+}
@@ -0,0 +1,380 @@
+use alloc::vec::Vec;
+
+use miden_objects::{
+    account::{AccountComponent, AccountStorage, StorageMap, StorageSlot},
+    AccountError, Digest, Felt, FieldElement, Hasher, Word, EMPTY_WORD,
+};
+
+use crate::account::components::blob_storage_library;
+
+// BLOB STORAGE ACCOUNT COMPONENT
+// ================================================================================================
+
+/// An [`AccountComponent`] storing an arbitrarily sized byte blob in the account storage.
+///
+/// The blob is split into chunks of [`BlobStorage::BYTES_PER_CHUNK`] bytes, each of which is
+/// encoded into a single [`Word`] (see [`encode_blob`]). The chunk with index `i` is stored in a
+/// storage map under the key `[0, 0, 0, i]`.
+///
+/// Its exported procedures are:
+/// - `write_blob`, which replaces the stored blob with the blob whose chunks are provided via the
+///   advice map under the blob commitment.
+/// - `read_chunk`, which returns the chunk with the specified index.
+/// - `get_blob_info`, which returns the commitment, the number of chunks and the byte length of the
+///   stored blob.
+/// - `clear_blob`, which removes the stored blob.
+///
+/// `write_blob` and `clear_blob` do not authenticate the caller. Thus, this component must be
+/// combined with a component providing authentication.
+///
+/// The storage layout of the component is:
+/// - Slot 0: Blob metadata stored as `[num_chunks, byte_len, 0, 0]`.
+/// - Slot 1: Blob commitment (see [`compute_blob_commitment`]).
+/// - Slot 2: Storage map holding the blob chunks.
+///
+/// This component supports all account types.
+pub struct BlobStorage {
+    blob: Vec<u8>,
+}
+
+impl BlobStorage {
+    // CONSTANTS
+    // --------------------------------------------------------------------------------------------
+
+    /// The number of blob bytes encoded into a single field element.
+    pub const BYTES_PER_ELEMENT: usize = 4;
+
+    /// The number of blob bytes encoded into a single chunk.
+    pub const BYTES_PER_CHUNK: usize = 4 * Self::BYTES_PER_ELEMENT;
+
+    /// The maximum number of chunks a blob can consist of.
+    ///
+    /// Note that the size of public account updates is further limited by
+    /// [`miden_objects::ACCOUNT_UPDATE_MAX_SIZE`].
+    pub const MAX_NUM_CHUNKS: usize = 1 << 16;
+
+    /// The maximum size of a blob in bytes.
+    pub const MAX_BLOB_SIZE: usize = Self::MAX_NUM_CHUNKS * Self::BYTES_PER_CHUNK;
+
+    /// The offset of the slot holding the blob metadata in this component's storage layout.
+    pub const METADATA_SLOT: u8 = 0;
+
+    /// The offset of the slot holding the blob commitment in this component's storage layout.
+    pub const COMMITMENT_SLOT: u8 = 1;
+
+    /// The offset of the slot holding the blob chunks in this component's storage layout.
+    pub const CHUNKS_SLOT: u8 = 2;
+
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Creates a new [`BlobStorage`] component initialized with the provided blob.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the blob exceeds [`BlobStorage::MAX_BLOB_SIZE`].
+    pub fn new(blob: Vec<u8>) -> Result<Self, AccountError> {
+        if blob.len() > Self::MAX_BLOB_SIZE {
+            return Err(AccountError::BlobStorageBlobTooLarge {
+                actual: blob.len(),
+                max: Self::MAX_BLOB_SIZE,
+            });
+        }
+
+        Ok(Self { blob })
+    }
+
+    /// Creates a new [`BlobStorage`] component which does not hold a blob.
+    pub fn empty() -> Self {
+        Self { blob: Vec::new() }
+    }
+
+    /// Reads the blob stored by a [`BlobStorage`] component from the provided account storage.
+    ///
+    /// `slot_offset` is the index of the first storage slot of the component in the account
+    /// storage.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - the storage slots at the given offset do not follow the layout of the component.
+    /// - the stored number of chunks exceeds [`BlobStorage::MAX_NUM_CHUNKS`].
+    /// - the stored chunks cannot be decoded into a blob of the stored length.
+    /// - the stored commitment does not match the commitment to the stored chunks.
+    pub fn read_from_storage(
+        storage: &AccountStorage,
+        slot_offset: u8,
+    ) -> Result<Self, AccountError> {
+        let metadata: Word = storage.get_item(slot_offset + Self::METADATA_SLOT)?.into();
+        let num_chunks = metadata[0].as_int();
+        let byte_len = metadata[1].as_int() as usize;
+
+        if num_chunks > Self::MAX_NUM_CHUNKS as u64 {
+            return Err(AccountError::BlobStorageTooManyChunks {
+                actual: num_chunks,
+                max: Self::MAX_NUM_CHUNKS,
+            });
+        }
+
+        let chunks = (0..num_chunks)
+            .map(|chunk_idx| {
+                storage.get_map_item(slot_offset + Self::CHUNKS_SLOT, chunk_key(chunk_idx))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let blob = decode_blob(&chunks, byte_len)?;
+
+        let stored_commitment = storage.get_item(slot_offset + Self::COMMITMENT_SLOT)?;
+        let chunks_commitment = compute_blob_commitment(&chunks);
+        if stored_commitment != chunks_commitment {
+            return Err(AccountError::BlobStorageCommitmentMismatch {
+                expected: stored_commitment,
+                actual: chunks_commitment,
+            });
+        }
+
+        Self::new(blob)
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the blob held by this component.
+    pub fn blob(&self) -> &[u8] {
+        &self.blob
+    }
+
+    /// Returns the chunks of the blob held by this component.
+    pub fn chunks(&self) -> Vec<Word> {
+        encode_blob(&self.blob)
+    }
+
+    /// Returns the commitment to the blob held by this component.
+    pub fn commitment(&self) -> Digest {
+        compute_blob_commitment(&self.chunks())
+    }
+
+    /// Returns the metadata of the blob held by this component as it is stored in the account
+    /// storage, i.e. `[num_chunks, byte_len, 0, 0]`.
+    pub fn metadata(&self) -> Word {
+        let num_chunks = self.blob.len().div_ceil(Self::BYTES_PER_CHUNK);
+        [
+            Felt::from(num_chunks as u32),
+            Felt::from(self.blob.len() as u32),
+            Felt::ZERO,
+            Felt::ZERO,
+        ]
+    }
+
+    /// Returns the advice map entry expected by the `write_blob` procedure to write the blob held
+    /// by this component, i.e. the blob commitment mapped to the flattened blob chunks.
+    pub fn advice_map_entry(&self) -> (Digest, Vec<Felt>) {
+        let chunks = self.chunks();
+        (compute_blob_commitment(&chunks), chunks.into_iter().flatten().collect())
+    }
+}
+
+impl From<BlobStorage> for AccountComponent {
+    fn from(blob_storage: BlobStorage) -> Self {
+        let chunks = blob_storage.chunks();
+        let chunk_map = StorageMap::with_entries(
+            chunks
+                .iter()
+                .enumerate()
+                .map(|(chunk_idx, chunk)| (chunk_key(chunk_idx as u64).into(), *chunk)),
+        );
+
+        let storage_slots = vec![
+            StorageSlot::Value(blob_storage.metadata()),
+            StorageSlot::Value(compute_blob_commitment(&chunks).into()),
+            StorageSlot::Map(chunk_map),
+        ];
+
+        AccountComponent::new(blob_storage_library(), storage_slots)
+            .expect("blob storage component should satisfy the requirements of a valid account component")
+            .with_supports_all_types()
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Encodes the provided blob into chunks.
+///
+/// Every group of [`BlobStorage::BYTES_PER_ELEMENT`] bytes is interpreted as a little-endian `u32`
+/// and stored in a single field element, and every group of four elements forms a chunk. The last
+/// chunk is padded with zeros if necessary.
+pub fn encode_blob(blob: &[u8]) -> Vec<Word> {
+    blob.chunks(BlobStorage::BYTES_PER_CHUNK)
+        .map(|chunk_bytes| {
+            let mut chunk = EMPTY_WORD;
+            for (element, element_bytes) in
+                chunk.iter_mut().zip(chunk_bytes.chunks(BlobStorage::BYTES_PER_ELEMENT))
+            {
+                let mut buf = [0u8; BlobStorage::BYTES_PER_ELEMENT];
+                buf[..element_bytes.len()].copy_from_slice(element_bytes);
+                *element = Felt::from(u32::from_le_bytes(buf));
+            }
+            chunk
+        })
+        .collect()
+}
+
+/// Decodes a blob of `byte_len` bytes from the provided chunks.
+///
+/// This is the inverse of [`encode_blob`].
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - the number of chunks does not match `byte_len`.
+/// - any of the chunk elements is not a valid `u32` value.
+pub fn decode_blob(chunks: &[Word], byte_len: usize) -> Result<Vec<u8>, AccountError> {
+    if chunks.len() != byte_len.div_ceil(BlobStorage::BYTES_PER_CHUNK) {
+        return Err(AccountError::BlobStorageChunkCountMismatch {
+            byte_len,
+            num_chunks: chunks.len(),
+        });
+    }
+
+    let mut blob = Vec::with_capacity(chunks.len() * BlobStorage::BYTES_PER_CHUNK);
+    for element in chunks.iter().flatten() {
+        let value = u32::try_from(element.as_int())
+            .map_err(|_| AccountError::BlobStorageInvalidChunkElement(*element))?;
+        blob.extend_from_slice(&value.to_le_bytes());
+    }
+    blob.truncate(byte_len);
+
+    Ok(blob)
+}
+
+/// Computes the commitment to a blob consisting of the provided chunks.
+///
+/// The commitment is the sequential hash of the chunk elements, which is the commitment the
+/// `write_blob` procedure verifies the advice map data against. The commitment to an empty blob is
+/// the empty word.
+pub fn compute_blob_commitment(chunks: &[Word]) -> Digest {
+    if chunks.is_empty() {
+        return Digest::default();
+    }
+
+    let elements: Vec<Felt> = chunks.iter().flatten().copied().collect();
+    Hasher::hash_elements(&elements)
+}
+
+/// Returns the storage map key under which the chunk with the specified index is stored.
+fn chunk_key(chunk_idx: u64) -> Word {
+    [Felt::ZERO, Felt::ZERO, Felt::ZERO, Felt::new(chunk_idx)]
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use miden_objects::{
+        account::{AccountBuilder, AccountType},
+        crypto::dsa::rpo_falcon512,
+        ONE,
+    };
+
+    use super::*;
+    use crate::account::auth::RpoFalcon512;
+
+    #[test]
+    fn blob_encoding_roundtrip() {
+        for len in [0usize, 1, 4, 15, 16, 17, 100] {
+            let blob: Vec<u8> = (0..len).map(|byte| byte as u8).collect();
+            let chunks = encode_blob(&blob);
+
+            assert_eq!(chunks.len(), len.div_ceil(BlobStorage::BYTES_PER_CHUNK));
+            assert_eq!(decode_blob(&chunks, len).unwrap(), blob);
+        }
+    }
+
+    #[test]
+    fn blob_decoding_fails_on_invalid_chunks() {
+        let chunks = encode_blob(&[1u8; 20]);
+        assert!(matches!(
+            decode_blob(&chunks, 40),
+            Err(AccountError::BlobStorageChunkCountMismatch { byte_len: 40, num_chunks: 2 })
+        ));
+
+        let chunks = [[Felt::new(u32::MAX as u64 + 1), Felt::ZERO, Felt::ZERO, Felt::ZERO]];
+        assert!(matches!(
+            decode_blob(&chunks, 4),
+            Err(AccountError::BlobStorageInvalidChunkElement(_))
+        ));
+    }
+
+    #[test]
+    fn blob_storage_component_roundtrip() {
+        let blob: Vec<u8> = (0..50).collect();
+        let blob_storage = BlobStorage::new(blob.clone()).unwrap();
+        let commitment = blob_storage.commitment();
+
+        let account = AccountBuilder::new([7; 32])
+            .account_type(AccountType::RegularAccountUpdatableCode)
+            .with_component(RpoFalcon512::new(rpo_falcon512::PublicKey::new([ONE; 4])))
+            .with_component(blob_storage)
+            .build_existing()
+            .unwrap();
+
+        // The falcon auth component is added first so the blob storage slots start at index 1.
+        assert_eq!(
+            account.storage().get_item(1).unwrap(),
+            [Felt::new(4), Felt::new(50), Felt::ZERO, Felt::ZERO].into()
+        );
+        assert_eq!(account.storage().get_item(2).unwrap(), commitment);
+
+        let read_blob = BlobStorage::read_from_storage(account.storage(), 1).unwrap();
+        assert_eq!(read_blob.blob(), blob);
+    }
+
+    #[test]
+    fn blob_storage_rejects_invalid_stored_blob() {
+        let blob_storage = BlobStorage::new((0..20).collect()).unwrap();
+        let chunk_map = StorageMap::with_entries(
+            blob_storage
+                .chunks()
+                .into_iter()
+                .enumerate()
+                .map(|(chunk_idx, chunk)| (chunk_key(chunk_idx as u64).into(), chunk)),
+        );
+
+        // A stored chunk count above the maximum is rejected before any chunk is read.
+        let num_chunks = BlobStorage::MAX_NUM_CHUNKS as u64 + 1;
+        let metadata = [Felt::new(num_chunks), Felt::new(20), Felt::ZERO, Felt::ZERO];
+        let storage = AccountStorage::new(vec![
+            StorageSlot::Value(metadata),
+            StorageSlot::Value(blob_storage.commitment().into()),
+            StorageSlot::Map(chunk_map.clone()),
+        ])
+        .unwrap();
+        assert!(matches!(
+            BlobStorage::read_from_storage(&storage, 0),
+            Err(AccountError::BlobStorageTooManyChunks { actual, .. }) if actual == num_chunks
+        ));
+
+        // A stored commitment which does not commit to the stored chunks is rejected.
+        let storage = AccountStorage::new(vec![
+            StorageSlot::Value(blob_storage.metadata()),
+            StorageSlot::Value([ONE; 4]),
+            StorageSlot::Map(chunk_map),
+        ])
+        .unwrap();
+        assert!(matches!(
+            BlobStorage::read_from_storage(&storage, 0),
+            Err(AccountError::BlobStorageCommitmentMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn blob_storage_rejects_oversized_blob() {
+        let blob = vec![0u8; BlobStorage::MAX_BLOB_SIZE + 1];
+        assert!(matches!(
+            BlobStorage::new(blob),
+            Err(AccountError::BlobStorageBlobTooLarge { .. })
+        ));
+    }
+}
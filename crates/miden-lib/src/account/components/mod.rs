@@ -17,6 +17,16 @@ static RPO_FALCON_512_LIBRARY: LazyLock<Library> = LazyLock::new(|| {
     Library::read_from_bytes(bytes).expect("Shipped Rpo Falcon 512 library is well-formed")
 });
 
+// Initialize the upgradable Rpo Falcon 512 library only once.
+static RPO_FALCON_512_UPGRADABLE_LIBRARY: LazyLock<Library> = LazyLock::new(|| {
+    let bytes = include_bytes!(concat!(
+        env!("OUT_DIR"),
+        "/assets/account_components/rpo_falcon_512_upgradable.masl"
+    ));
+    Library::read_from_bytes(bytes)
+        .expect("Shipped upgradable Rpo Falcon 512 library is well-formed")
+});
+
 // Initialize the Basic Fungible Faucet library only once.
 static BASIC_FUNGIBLE_FAUCET_LIBRARY: LazyLock<Library> = LazyLock::new(|| {
     let bytes = include_bytes!(concat!(
@@ -26,6 +36,20 @@ static BASIC_FUNGIBLE_FAUCET_LIBRARY: LazyLock<Library> = LazyLock::new(|| {
     Library::read_from_bytes(bytes).expect("Shipped Basic Fungible Faucet library is well-formed")
 });
 
+// Initialize the Blob Storage library only once.
+static BLOB_STORAGE_LIBRARY: LazyLock<Library> = LazyLock::new(|| {
+    let bytes =
+        include_bytes!(concat!(env!("OUT_DIR"), "/assets/account_components/blob_storage.masl"));
+    Library::read_from_bytes(bytes).expect("Shipped Blob Storage library is well-formed")
+});
+
+// Initialize the Price Oracle library only once.
+static PRICE_ORACLE_LIBRARY: LazyLock<Library> = LazyLock::new(|| {
+    let bytes =
+        include_bytes!(concat!(env!("OUT_DIR"), "/assets/account_components/price_oracle.masl"));
+    Library::read_from_bytes(bytes).expect("Shipped Price Oracle library is well-formed")
+});
+
 /// Returns the Basic Wallet Library.
 pub fn basic_wallet_library() -> Library {
     BASIC_WALLET_LIBRARY.clone()
@@ -36,7 +60,22 @@ pub fn rpo_falcon_512_library() -> Library {
     RPO_FALCON_512_LIBRARY.clone()
 }
 
+/// Returns the upgradable Rpo Falcon 512 Library.
+pub fn rpo_falcon_512_upgradable_library() -> Library {
+    RPO_FALCON_512_UPGRADABLE_LIBRARY.clone()
+}
+
 /// Returns the Basic Fungible Faucet Library.
 pub fn basic_fungible_faucet_library() -> Library {
     BASIC_FUNGIBLE_FAUCET_LIBRARY.clone()
 }
+
+/// Returns the Blob Storage Library.
+pub fn blob_storage_library() -> Library {
+    BLOB_STORAGE_LIBRARY.clone()
+}
+
+/// Returns the Price Oracle Library.
+pub fn price_oracle_library() -> Library {
+    PRICE_ORACLE_LIBRARY.clone()
+}
@@ -1,14 +1,19 @@
 use miden_objects::{
     account::{
-        Account, AccountBuilder, AccountComponent, AccountIdAnchor, AccountStorageMode,
-        AccountType, StorageSlot,
+        Account, AccountBuilder, AccountComponent, AccountId, AccountIdAnchor, AccountStorage,
+        AccountStorageMode, AccountType, StorageMap, StorageSlot,
     },
     asset::{FungibleAsset, TokenSymbol},
-    AccountError, Felt, FieldElement, Word,
+    transaction::TransactionScript,
+    AccountError, Felt, FieldElement, TransactionScriptError, Word,
 };
 
 use super::AuthScheme;
-use crate::account::{auth::RpoFalcon512, components::basic_fungible_faucet_library};
+use crate::{
+    account::{auth::RpoFalcon512, components::basic_fungible_faucet_library},
+    note::scripts,
+    transaction::TransactionKernel,
+};
 
 // BASIC FUNGIBLE FAUCET ACCOUNT COMPONENT
 // ================================================================================================
@@ -18,16 +23,35 @@ use crate::account::{auth::RpoFalcon512, components::basic_fungible_faucet_libra
 /// Its exported procedures are:
 /// - `distribute`, which mints an assets and create a note for the provided recipient.
 /// - `burn`, which burns the provided asset.
+/// - `set_paused`, which pauses or resumes the distribution of assets.
+/// - `set_recipient_mint_cap`, which sets or removes the maximum amount of assets which can be
+///   distributed to a single recipient account.
+/// - `set_max_supply`, which lowers the maximum supply of the faucet.
+///
+/// `distribute` and the policy setters require authentication while `burn` does not require
+/// authentication and can be called by anyone. Thus, this component must be combined with a
+/// component providing authentication.
+///
+/// While a recipient mint cap is set, `distribute` only creates P2ID notes. It takes the ID of
+/// the account the note is intended for and the serial number of the note, and checks that the
+/// note recipient is the P2ID recipient built from them. The recipient mint cap then limits the
+/// total amount distributed to each such account.
 ///
-/// `distribute` requires authentication while `burn` does not require authentication and can be
-/// called by anyone. Thus, this component must be combined with a component providing
-/// authentication.
+/// The storage layout of the component is:
+/// - Slot 0: Token metadata as `[max_supply, decimals, token_symbol, 0]`.
+/// - Slot 1: Issuance policies as `[paused, recipient_mint_cap, 0, 0]`, where a mint cap of zero
+///   means the amount distributed to a single recipient account is not limited.
+/// - Slot 2: Storage map from `[0, 0, account_id_suffix, account_id_prefix]` to `[minted, 0, 0,
+///   0]`.
+/// - Slot 3: Root of the P2ID note script.
 ///
 /// This component supports accounts of type [`AccountType::FungibleFaucet`].
 pub struct BasicFungibleFaucet {
     symbol: TokenSymbol,
     decimals: u8,
     max_supply: Felt,
+    recipient_mint_cap: Option<Felt>,
+    paused: bool,
 }
 
 impl BasicFungibleFaucet {
@@ -37,10 +61,27 @@ impl BasicFungibleFaucet {
     /// The maximum number of decimals supported by the component.
     pub const MAX_DECIMALS: u8 = 12;
 
+    /// The offset of the slot holding the token metadata in this component's storage layout.
+    pub const METADATA_SLOT: u8 = 0;
+
+    /// The offset of the slot holding the issuance policies in this component's storage layout.
+    pub const POLICY_SLOT: u8 = 1;
+
+    /// The offset of the slot holding the amounts minted per recipient account in this
+    /// component's storage layout.
+    pub const RECIPIENT_MINTED_SLOT: u8 = 2;
+
+    /// The offset of the slot holding the root of the P2ID note script in this component's
+    /// storage layout.
+    pub const P2ID_SCRIPT_ROOT_SLOT: u8 = 3;
+
     // CONSTRUCTORS
     // --------------------------------------------------------------------------------------------
 
     /// Creates a new [`BasicFungibleFaucet`] component from the given pieces of metadata.
+    ///
+    /// The distribution of assets is not paused and the amount distributed to a single recipient
+    /// account is not limited.
     pub fn new(symbol: TokenSymbol, decimals: u8, max_supply: Felt) -> Result<Self, AccountError> {
         // First check that the metadata is valid.
         if decimals > Self::MAX_DECIMALS {
@@ -55,7 +96,124 @@ impl BasicFungibleFaucet {
             });
         }
 
-        Ok(Self { symbol, decimals, max_supply })
+        Ok(Self {
+            symbol,
+            decimals,
+            max_supply,
+            recipient_mint_cap: None,
+            paused: false,
+        })
+    }
+
+    /// Limits the amount of assets which can be distributed to a single recipient account to the
+    /// provided cap.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the cap is zero or greater than [`FungibleAsset::MAX_AMOUNT`].
+    pub fn with_recipient_mint_cap(mut self, cap: Felt) -> Result<Self, AccountError> {
+        if cap == Felt::ZERO || cap.as_int() > FungibleAsset::MAX_AMOUNT {
+            return Err(AccountError::FungibleFaucetInvalidRecipientMintCap {
+                actual: cap.as_int(),
+                max: FungibleAsset::MAX_AMOUNT,
+            });
+        }
+
+        self.recipient_mint_cap = Some(cap);
+        Ok(self)
+    }
+
+    /// Sets whether the distribution of assets is initially paused.
+    pub fn with_paused(mut self, paused: bool) -> Self {
+        self.paused = paused;
+        self
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the symbol of the faucet's token.
+    pub fn symbol(&self) -> TokenSymbol {
+        self.symbol
+    }
+
+    /// Returns the number of decimals of the faucet's token.
+    pub fn decimals(&self) -> u8 {
+        self.decimals
+    }
+
+    /// Returns the maximum supply of the faucet's token.
+    pub fn max_supply(&self) -> Felt {
+        self.max_supply
+    }
+
+    /// Returns the maximum amount of assets which can be distributed to a single recipient
+    /// account, or `None` if the amount is not limited.
+    pub fn recipient_mint_cap(&self) -> Option<Felt> {
+        self.recipient_mint_cap
+    }
+
+    /// Returns `true` if the distribution of assets is paused.
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    // OWNER HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the transaction script pausing or resuming the distribution of assets by the
+    /// faucet.
+    ///
+    /// The script authenticates the transaction using the RpoFalcon512 authentication component
+    /// of the faucet.
+    pub fn set_paused_script(paused: bool) -> Result<TransactionScript, TransactionScriptError> {
+        policy_update_script("set_paused", Felt::from(paused))
+    }
+
+    /// Returns the transaction script setting the maximum amount of assets which can be
+    /// distributed to a single recipient account. `None` removes the mint cap.
+    ///
+    /// The script authenticates the transaction using the RpoFalcon512 authentication component
+    /// of the faucet.
+    pub fn set_recipient_mint_cap_script(
+        cap: Option<Felt>,
+    ) -> Result<TransactionScript, TransactionScriptError> {
+        policy_update_script("set_recipient_mint_cap", cap.unwrap_or(Felt::ZERO))
+    }
+
+    /// Returns the transaction script lowering the maximum supply of the faucet.
+    ///
+    /// The script authenticates the transaction using the RpoFalcon512 authentication component
+    /// of the faucet. The transaction fails if the new maximum supply is greater than the current
+    /// one or lower than the total issuance of the faucet.
+    pub fn set_max_supply_script(
+        max_supply: Felt,
+    ) -> Result<TransactionScript, TransactionScriptError> {
+        policy_update_script("set_max_supply", max_supply)
+    }
+
+    // CONSUMER HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Reads the amount of assets distributed so far to the provided recipient account from the
+    /// storage of a basic fungible faucet account.
+    ///
+    /// `slot_offset` is the index of the first storage slot of the component in the account
+    /// storage. Amounts are only recorded while a recipient mint cap is set.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the storage slots at the given offset do not follow the layout of the
+    /// component.
+    pub fn read_recipient_minted(
+        storage: &AccountStorage,
+        slot_offset: u8,
+        recipient: AccountId,
+    ) -> Result<Felt, AccountError> {
+        let key = [Felt::ZERO, Felt::ZERO, recipient.suffix(), recipient.prefix().as_felt()];
+        let minted = storage.get_map_item(slot_offset + Self::RECIPIENT_MINTED_SLOT, key)?;
+
+        Ok(minted[0])
     }
 }
 
@@ -65,10 +223,24 @@ impl From<BasicFungibleFaucet> for AccountComponent {
         // [a3, a2, a1, a0, ...]
         let metadata =
             [faucet.max_supply, Felt::from(faucet.decimals), faucet.symbol.into(), Felt::ZERO];
+        let policy: Word = [
+            Felt::from(faucet.paused),
+            faucet.recipient_mint_cap.unwrap_or(Felt::ZERO),
+            Felt::ZERO,
+            Felt::ZERO,
+        ];
 
-        AccountComponent::new(basic_fungible_faucet_library(), vec![StorageSlot::Value(metadata)])
-            .expect("basic fungible faucet component should satisfy the requirements of a valid account component")
-            .with_supported_type(AccountType::FungibleFaucet)
+        AccountComponent::new(
+            basic_fungible_faucet_library(),
+            vec![
+                StorageSlot::Value(metadata),
+                StorageSlot::Value(policy),
+                StorageSlot::Map(StorageMap::new()),
+                StorageSlot::Value(scripts::p2id().hash().into()),
+            ],
+        )
+        .expect("basic fungible faucet component should satisfy the requirements of a valid account component")
+        .with_supported_type(AccountType::FungibleFaucet)
     }
 }
 
@@ -79,17 +251,24 @@ impl From<BasicFungibleFaucet> for AccountComponent {
 /// account storage type, specified authentication scheme, and provided meta data (token symbol,
 /// decimals, max supply).
 ///
-/// The basic faucet interface exposes two procedures:
+/// The basic faucet interface exposes the procedures of the [`BasicFungibleFaucet`] component,
+/// which include:
 /// - `distribute`, which mints an assets and create a note for the provided recipient.
 /// - `burn`, which burns the provided asset.
 ///
 /// `distribute` requires authentication. The authentication procedure is defined by the specified
 /// authentication scheme. `burn` does not require authentication and can be called by anyone.
+/// The distribution of assets is not paused and the amount distributed to a single recipient
+/// account is not limited. To create a faucet with other issuance policies, use an
+/// [`AccountBuilder`] with a configured [`BasicFungibleFaucet`] component.
 ///
 /// The storage layout of the faucet account is:
 /// - Slot 0: Reserved slot for faucets.
 /// - Slot 1: Public Key of the authentication component.
 /// - Slot 2: Token metadata of the faucet.
+/// - Slot 3: Issuance policies of the faucet.
+/// - Slot 4: Amounts minted per recipient account.
+/// - Slot 5: Root of the P2ID note script.
 pub fn create_basic_fungible_faucet(
     init_seed: [u8; 32],
     id_anchor: AccountIdAnchor,
@@ -116,6 +295,38 @@ pub fn create_basic_fungible_faucet(
     Ok((account, account_seed))
 }
 
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the transaction script calling the specified policy setter of the faucet with the
+/// provided value and authenticating the transaction.
+fn policy_update_script(
+    procedure: &str,
+    value: Felt,
+) -> Result<TransactionScript, TransactionScriptError> {
+    let script = format!(
+        "
+        begin
+            # pad the stack before call
+            padw padw padw push.0.0.0
+            push.{value}
+            # => [value, pad(15)]
+
+            call.::miden::contracts::faucets::basic_fungible::{procedure}
+            # => [pad(16)]
+
+            call.::miden::contracts::auth::basic::auth_tx_rpo_falcon512
+            # => [pad(16)]
+
+            # truncate the stack
+            dropw dropw dropw dropw
+        end
+        "
+    );
+
+    TransactionScript::compile(script, [], TransactionKernel::assembler())
+}
+
 // TESTS
 // ================================================================================================
 
@@ -126,7 +337,10 @@ mod tests {
     };
     use vm_processor::Word;
 
-    use super::{create_basic_fungible_faucet, AccountStorageMode, AuthScheme, Felt, TokenSymbol};
+    use super::{
+        create_basic_fungible_faucet, scripts, AccountBuilder, AccountError, AccountStorageMode,
+        AccountType, AuthScheme, BasicFungibleFaucet, Felt, FungibleAsset, TokenSymbol,
+    };
 
     #[test]
     fn faucet_contract_creation() {
@@ -178,6 +392,54 @@ mod tests {
             [Felt::new(123), Felt::new(2), token_symbol.into(), Felt::ZERO].into()
         );
 
+        // The distribution of assets should not be paused and the mint cap should not be set.
+        assert_eq!(faucet_account.storage().get_item(3).unwrap(), Word::default().into());
+
         assert!(faucet_account.is_faucet());
     }
+
+    #[test]
+    fn faucet_component_policies() {
+        let symbol = TokenSymbol::try_from("POL").unwrap();
+        let faucet = BasicFungibleFaucet::new(symbol, 2, Felt::new(1000))
+            .unwrap()
+            .with_recipient_mint_cap(Felt::new(100))
+            .unwrap()
+            .with_paused(true);
+
+        let account = AccountBuilder::new([7; 32])
+            .account_type(AccountType::FungibleFaucet)
+            .with_component(faucet)
+            .build_existing()
+            .unwrap();
+
+        assert_eq!(
+            account.storage().get_item(1 + BasicFungibleFaucet::POLICY_SLOT).unwrap(),
+            [ONE, Felt::new(100), Felt::ZERO, Felt::ZERO].into()
+        );
+        assert_eq!(
+            BasicFungibleFaucet::read_recipient_minted(account.storage(), 1, account.id()).unwrap(),
+            Felt::ZERO
+        );
+        assert_eq!(
+            account
+                .storage()
+                .get_item(1 + BasicFungibleFaucet::P2ID_SCRIPT_ROOT_SLOT)
+                .unwrap(),
+            scripts::p2id().hash()
+        );
+
+        let faucet = BasicFungibleFaucet::new(symbol, 2, Felt::new(1000)).unwrap();
+        assert_eq!(faucet.recipient_mint_cap(), None);
+        assert!(!faucet.is_paused());
+
+        for cap in [Felt::ZERO, Felt::new(FungibleAsset::MAX_AMOUNT + 1)] {
+            assert!(matches!(
+                BasicFungibleFaucet::new(symbol, 2, Felt::new(1000))
+                    .unwrap()
+                    .with_recipient_mint_cap(cap),
+                Err(AccountError::FungibleFaucetInvalidRecipientMintCap { .. })
+            ));
+        }
+    }
 }
@@ -1,6 +1,8 @@
 use super::auth::AuthScheme;
 
 pub mod auth;
+pub mod blob;
 pub(super) mod components;
 pub mod faucets;
+pub mod oracles;
 pub mod wallets;
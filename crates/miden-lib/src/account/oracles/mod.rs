@@ -0,0 +1,412 @@
+use alloc::{format, string::String, vec::Vec};
+
+use miden_objects::{
+    account::{Account, AccountComponent, AccountId, AccountStorage, StorageMap, StorageSlot},
+    asset::TokenSymbol,
+    crypto::{
+        dsa::rpo_falcon512::PublicKey,
+        merkle::{MerkleError, MerklePath},
+    },
+    transaction::TransactionScript,
+    utils::{prepare_word, sync::LazyLock},
+    vm::AdviceInputs,
+    AccountError, Digest, Felt, FieldElement, Hasher, TransactionScriptError, Word,
+};
+
+use crate::{account::components::price_oracle_library, transaction::TransactionKernel};
+
+// Find the MAST root of the `get_price` procedure only once
+static GET_PRICE_ROOT: LazyLock<Digest> = LazyLock::new(|| {
+    let library = price_oracle_library();
+    let export = library
+        .exports()
+        .find(|export| export.name.as_str() == "get_price")
+        .expect("price oracle library should export get_price");
+
+    library.mast_forest()[library.get_export_node_id(export)].digest()
+});
+
+// PRICE PAIR
+// ================================================================================================
+
+/// A pair of tokens for which a [`PriceOracle`] stores prices.
+///
+/// The price of a pair is the amount of `quote` tokens a single `base` token is worth.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PricePair {
+    base: TokenSymbol,
+    quote: TokenSymbol,
+}
+
+impl PricePair {
+    /// Creates a new [`PricePair`] from the provided base and quote token symbols.
+    pub fn new(base: TokenSymbol, quote: TokenSymbol) -> Self {
+        Self { base, quote }
+    }
+
+    /// Returns the symbol of the base token of this pair.
+    pub fn base(&self) -> TokenSymbol {
+        self.base
+    }
+
+    /// Returns the symbol of the quote token of this pair.
+    pub fn quote(&self) -> TokenSymbol {
+        self.quote
+    }
+
+    /// Returns the key under which the price entry of this pair is stored in the oracle storage
+    /// map, i.e. `[base, quote, 0, 0]`.
+    pub fn key(&self) -> Word {
+        [self.base.into(), self.quote.into(), Felt::ZERO, Felt::ZERO]
+    }
+}
+
+// PRICE ENTRY
+// ================================================================================================
+
+/// A price entry stored by a [`PriceOracle`].
+///
+/// The entry is stored as `[price, decimals, timestamp, 0]`, where the actual price is
+/// `price / 10^decimals`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PriceEntry {
+    price: Felt,
+    decimals: u8,
+    timestamp: u32,
+}
+
+impl PriceEntry {
+    /// Creates a new [`PriceEntry`] from the provided price, number of decimals and timestamp.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the price is zero.
+    pub fn new(price: Felt, decimals: u8, timestamp: u32) -> Result<Self, AccountError> {
+        if price == Felt::ZERO {
+            return Err(AccountError::PriceOracleZeroPrice);
+        }
+
+        Ok(Self { price, decimals, timestamp })
+    }
+
+    /// Returns the price of this entry.
+    pub fn price(&self) -> Felt {
+        self.price
+    }
+
+    /// Returns the number of decimals of the price of this entry.
+    pub fn decimals(&self) -> u8 {
+        self.decimals
+    }
+
+    /// Returns the timestamp of this entry.
+    pub fn timestamp(&self) -> u32 {
+        self.timestamp
+    }
+
+    /// Returns the message the publisher has to sign for this entry to be accepted for the
+    /// specified pair by the specified oracle account, i.e.
+    /// `hash(hash(PAIR_KEY, PRICE_ENTRY), ORACLE_ID)` where `ORACLE_ID` is
+    /// `[oracle_id_prefix, oracle_id_suffix, 0, 0]`.
+    ///
+    /// Binding the oracle account ID prevents an entry signed for one oracle from being pushed to
+    /// another oracle trusting the same publisher.
+    pub fn signing_message(&self, oracle_id: AccountId, pair: &PricePair) -> Word {
+        let entry_hash = Hasher::merge(&[pair.key().into(), Word::from(*self).into()]);
+        let oracle_id = [oracle_id.prefix().as_felt(), oracle_id.suffix(), Felt::ZERO, Felt::ZERO];
+
+        Hasher::merge(&[entry_hash, oracle_id.into()]).into()
+    }
+}
+
+impl From<PriceEntry> for Word {
+    fn from(entry: PriceEntry) -> Self {
+        [entry.price, Felt::from(entry.decimals), Felt::from(entry.timestamp), Felt::ZERO]
+    }
+}
+
+impl TryFrom<Word> for PriceEntry {
+    type Error = AccountError;
+
+    fn try_from(word: Word) -> Result<Self, Self::Error> {
+        let decimals = u8::try_from(word[1].as_int())
+            .map_err(|_| AccountError::PriceOracleInvalidPriceEntry(word))?;
+        let timestamp = u32::try_from(word[2].as_int())
+            .map_err(|_| AccountError::PriceOracleInvalidPriceEntry(word))?;
+
+        Self::new(word[0], decimals, timestamp)
+    }
+}
+
+// PRICE ORACLE ACCOUNT COMPONENT
+// ================================================================================================
+
+/// An [`AccountComponent`] implementing a price oracle.
+///
+/// Price entries are signed by the publisher of the oracle and stored in a storage map keyed by
+/// [`PricePair::key`].
+///
+/// Its exported procedures are:
+/// - `push_price`, which stores a price entry for a pair after verifying the signature of the
+///   publisher over it.
+/// - `get_price`, which returns the price entry of a pair. It is intended to be invoked by other
+///   accounts via foreign procedure invocation.
+///
+/// `push_price` only verifies the publisher's signature over the pushed entry and does not
+/// authenticate the caller. Thus, this component must be combined with a component providing
+/// authentication.
+///
+/// The storage layout of the component is:
+/// - Slot 0: Public key of the publisher.
+/// - Slot 1: Storage map of the price entries.
+///
+/// This component supports all account types.
+pub struct PriceOracle {
+    publisher_key: PublicKey,
+    prices: Vec<(PricePair, PriceEntry)>,
+}
+
+impl PriceOracle {
+    // CONSTANTS
+    // --------------------------------------------------------------------------------------------
+
+    /// The offset of the slot holding the publisher public key in this component's storage
+    /// layout.
+    pub const PUBLISHER_KEY_SLOT: u8 = 0;
+
+    /// The offset of the slot holding the price entries in this component's storage layout.
+    pub const PRICES_SLOT: u8 = 1;
+
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Creates a new [`PriceOracle`] component accepting price entries signed by the provided
+    /// publisher key.
+    pub fn new(publisher_key: PublicKey) -> Self {
+        Self { publisher_key, prices: Vec::new() }
+    }
+
+    /// Initializes the price oracle with the provided price entries.
+    pub fn with_prices(
+        mut self,
+        prices: impl IntoIterator<Item = (PricePair, PriceEntry)>,
+    ) -> Self {
+        self.prices.extend(prices);
+        self
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the public key of the publisher of this oracle.
+    pub fn publisher_key(&self) -> PublicKey {
+        self.publisher_key
+    }
+
+    // OPERATOR HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the transaction script pushing the provided price entry for the specified pair to
+    /// the oracle account with the specified ID.
+    ///
+    /// `signature` is the publisher's signature over [`PriceEntry::signing_message`], encoded as
+    /// expected on the advice stack by `std::crypto::dsa::rpo_falcon512::verify`. It is provided
+    /// to the transaction via the advice map of the script.
+    ///
+    /// The script authenticates the transaction using the RpoFalcon512 authentication component
+    /// of the oracle.
+    pub fn push_price_script(
+        &self,
+        oracle_id: AccountId,
+        pair: &PricePair,
+        entry: &PriceEntry,
+        signature: Vec<Felt>,
+    ) -> Result<TransactionScript, TransactionScriptError> {
+        let signature_key = Hasher::merge(&[
+            Word::from(self.publisher_key).into(),
+            entry.signing_message(oracle_id, pair).into(),
+        ]);
+
+        let script = format!(
+            "
+            begin
+                # pad the stack before call
+                padw padw
+                push.{entry} push.{pair_key}
+                # => [PAIR_KEY, PRICE_ENTRY, pad(8)]
+
+                call.::miden::contracts::oracles::price::push_price
+                # => [pad(16)]
+
+                call.::miden::contracts::auth::basic::auth_tx_rpo_falcon512
+                # => [pad(16)]
+
+                # truncate the stack
+                dropw dropw dropw dropw
+            end
+            ",
+            entry = prepare_word(&(*entry).into()),
+            pair_key = prepare_word(&pair.key()),
+        );
+
+        TransactionScript::compile(
+            script,
+            [(signature_key.into(), signature)],
+            TransactionKernel::assembler(),
+        )
+    }
+
+    // CONSUMER HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the MAST root of the `get_price` procedure, i.e. the root of the procedure which
+    /// has to be invoked via foreign procedure invocation to read a price from an oracle account.
+    pub fn get_price_root() -> Digest {
+        *GET_PRICE_ROOT
+    }
+
+    /// Returns MASM code reading the price entry of the specified pair from the oracle account
+    /// with the specified ID via foreign procedure invocation.
+    ///
+    /// The code can be embedded into note or transaction scripts and leaves the price entry, i.e.
+    /// `[0, timestamp, decimals, price]`, on top of the stack. The advice inputs returned by
+    /// [`PriceOracle::fpi_advice_inputs`] must be provided to the transaction executing it.
+    pub fn get_price_via_fpi_code(oracle_id: AccountId, pair: &PricePair) -> String {
+        format!(
+            "
+            push.{pair_key}
+            push.{oracle_id_suffix}.{oracle_id_prefix}
+            # => [oracle_id_prefix, oracle_id_suffix, PAIR_KEY]
+
+            exec.::miden::contracts::oracles::price::get_price_via_fpi
+            # => [PRICE_ENTRY]
+            ",
+            pair_key = prepare_word(&pair.key()),
+            oracle_id_suffix = oracle_id.suffix(),
+            oracle_id_prefix = oracle_id.prefix().as_felt(),
+        )
+    }
+
+    /// Reads the price entry of the specified pair from the storage of a price oracle account.
+    ///
+    /// `slot_offset` is the index of the first storage slot of the component in the account
+    /// storage. Returns `None` if the oracle does not store an entry for the pair.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - the storage slots at the given offset do not follow the layout of the component.
+    /// - the stored entry is not a valid [`PriceEntry`].
+    pub fn read_price(
+        storage: &AccountStorage,
+        slot_offset: u8,
+        pair: &PricePair,
+    ) -> Result<Option<PriceEntry>, AccountError> {
+        let entry = storage.get_map_item(slot_offset + Self::PRICES_SLOT, pair.key())?;
+        if entry[0] == Felt::ZERO {
+            return Ok(None);
+        }
+
+        PriceEntry::try_from(entry).map(Some)
+    }
+
+    /// Returns the advice inputs required to invoke the procedures of the provided oracle account
+    /// via foreign procedure invocation.
+    ///
+    /// `account_path` is the authentication path of the oracle account in the account tree of the
+    /// transaction's reference block. Note that the code of the oracle account must also be loaded
+    /// into the transaction executor.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the inner nodes of the account path cannot be computed.
+    pub fn fpi_advice_inputs(
+        oracle_account: &Account,
+        account_path: &MerklePath,
+    ) -> Result<AdviceInputs, MerkleError> {
+        let mut advice_inputs = AdviceInputs::default();
+        TransactionKernel::extend_advice_inputs_for_account(
+            &mut advice_inputs,
+            &oracle_account.into(),
+            oracle_account.code(),
+            &oracle_account.storage().get_header(),
+            account_path,
+        )?;
+
+        for slot in oracle_account.storage().slots() {
+            if let StorageSlot::Map(map) = slot {
+                advice_inputs.extend_merkle_store(map.inner_nodes());
+                advice_inputs
+                    .extend_map(map.leaves().map(|(_, leaf)| (leaf.hash(), leaf.to_elements())));
+            }
+        }
+
+        Ok(advice_inputs)
+    }
+}
+
+impl From<PriceOracle> for AccountComponent {
+    fn from(oracle: PriceOracle) -> Self {
+        let prices = StorageMap::with_entries(
+            oracle
+                .prices
+                .iter()
+                .map(|(pair, entry)| (Digest::from(pair.key()), Word::from(*entry))),
+        );
+
+        AccountComponent::new(
+            price_oracle_library(),
+            vec![StorageSlot::Value(oracle.publisher_key.into()), StorageSlot::Map(prices)],
+        )
+        .expect(
+            "price oracle component should satisfy the requirements of a valid account component",
+        )
+        .with_supports_all_types()
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use miden_objects::{account::AccountBuilder, ONE};
+
+    use super::*;
+
+    #[test]
+    fn price_entry_word_roundtrip() {
+        let entry = PriceEntry::new(Felt::new(123_456), 4, 1_700_000_000).unwrap();
+        assert_eq!(PriceEntry::try_from(Word::from(entry)).unwrap(), entry);
+
+        assert!(matches!(
+            PriceEntry::new(Felt::ZERO, 4, 1_700_000_000),
+            Err(AccountError::PriceOracleZeroPrice)
+        ));
+        assert!(matches!(
+            PriceEntry::try_from([ONE, Felt::new(256), ONE, Felt::ZERO]),
+            Err(AccountError::PriceOracleInvalidPriceEntry(_))
+        ));
+    }
+
+    #[test]
+    fn price_oracle_component_storage() {
+        let publisher_key = PublicKey::new([ONE; 4]);
+        let btc_usd =
+            PricePair::new(TokenSymbol::new("BTC").unwrap(), TokenSymbol::new("USD").unwrap());
+        let eth_usd =
+            PricePair::new(TokenSymbol::new("ETH").unwrap(), TokenSymbol::new("USD").unwrap());
+        let entry = PriceEntry::new(Felt::new(9_500_000), 2, 100).unwrap();
+
+        let account = AccountBuilder::new([5; 32])
+            .with_component(PriceOracle::new(publisher_key).with_prices([(btc_usd, entry)]))
+            .build_existing()
+            .unwrap();
+
+        assert_eq!(account.storage().get_item(0).unwrap(), Word::from(publisher_key).into());
+        assert_eq!(PriceOracle::read_price(account.storage(), 0, &btc_usd).unwrap(), Some(entry));
+        assert_eq!(PriceOracle::read_price(account.storage(), 0, &eth_usd).unwrap(), None);
+
+        assert!(account.code().has_procedure(PriceOracle::get_price_root()));
+    }
+}
@@ -32,8 +32,21 @@ pub const ERR_ACCOUNT_TOO_MANY_PROCEDURES: u32 = 0x00020010;
 pub const ERR_ACCOUNT_TOO_MANY_STORAGE_SLOTS: u32 = 0x00020011;
 pub const ERR_ACCOUNT_TOTAL_ISSUANCE_PROC_CAN_ONLY_BE_CALLED_ON_FUNGIBLE_FAUCET: u32 = 0x00020001;
 
+pub const ERR_BLOB_STORAGE_BLOB_IS_EMPTY: u32 = 0x0002005C;
+pub const ERR_BLOB_STORAGE_CHUNK_COUNT_MISMATCH: u32 = 0x0002005E;
+pub const ERR_BLOB_STORAGE_TOO_MANY_CHUNKS: u32 = 0x0002005D;
+
 pub const ERR_EPILOGUE_TOTAL_NUMBER_OF_ASSETS_MUST_STAY_THE_SAME: u32 = 0x00020029;
 
+pub const ERR_ESCROW_CONSUMER_IS_NOT_ARBITER: u32 = 0x00020065;
+pub const ERR_ESCROW_CONSUMER_IS_NOT_BENEFICIARY: u32 = 0x00020064;
+pub const ERR_ESCROW_CONSUMER_IS_NOT_SENDER: u32 = 0x00020066;
+pub const ERR_ESCROW_INVALID_SPLIT_AMOUNT: u32 = 0x00020069;
+pub const ERR_ESCROW_REFUND_HEIGHT_NOT_REACHED: u32 = 0x00020067;
+pub const ERR_ESCROW_SPLIT_REQUIRES_SINGLE_FUNGIBLE_ASSET: u32 = 0x00020068;
+pub const ERR_ESCROW_UNKNOWN_ACTION: u32 = 0x00020063;
+pub const ERR_ESCROW_WRONG_NUMBER_OF_INPUTS: u32 = 0x00020062;
+
 pub const ERR_FAUCET_BURN_CANNOT_EXCEED_EXISTING_TOTAL_SUPPLY: u32 = 0x0002002B;
 pub const ERR_FAUCET_BURN_NON_FUNGIBLE_ASSET_CAN_ONLY_BE_CALLED_ON_NON_FUNGIBLE_FAUCET: u32 = 0x0002002D;
 pub const ERR_FAUCET_INVALID_STORAGE_OFFSET: u32 = 0x0002000E;
@@ -54,6 +67,13 @@ pub const ERR_FUNGIBLE_ASSET_FORMAT_ELEMENT_ONE_MUST_BE_ZERO: u32 = 0x00020020;
 pub const ERR_FUNGIBLE_ASSET_FORMAT_ELEMENT_TWO_AND_THREE_MUST_BE_FUNGIBLE_FAUCET_ID: u32 = 0x00020022;
 pub const ERR_FUNGIBLE_ASSET_FORMAT_ELEMENT_ZERO_MUST_BE_WITHIN_LIMITS: u32 = 0x00020023;
 pub const ERR_FUNGIBLE_ASSET_PROVIDED_FAUCET_ID_IS_INVALID: u32 = 0x0002004B;
+pub const ERR_FUNGIBLE_FAUCET_DISTRIBUTE_WOULD_EXCEED_RECIPIENT_MINT_CAP: u32 = 0x0002006B;
+pub const ERR_FUNGIBLE_FAUCET_DISTRIBUTION_IS_PAUSED: u32 = 0x0002006A;
+pub const ERR_FUNGIBLE_FAUCET_MAX_SUPPLY_BELOW_TOTAL_ISSUANCE: u32 = 0x0002006F;
+pub const ERR_FUNGIBLE_FAUCET_MAX_SUPPLY_CANNOT_BE_INCREASED: u32 = 0x0002006E;
+pub const ERR_FUNGIBLE_FAUCET_PAUSED_FLAG_MUST_BE_BOOLEAN: u32 = 0x0002006C;
+pub const ERR_FUNGIBLE_FAUCET_RECIPIENT_IS_NOT_P2ID_FOR_CAPPED_ACCOUNT: u32 = 0x00020070;
+pub const ERR_FUNGIBLE_FAUCET_RECIPIENT_MINT_CAP_EXCEEDS_MAX_AMOUNT: u32 = 0x0002006D;
 
 pub const ERR_KERNEL_PROCEDURE_OFFSET_OUT_OF_BOUNDS: u32 = 0x00020003;
 
@@ -74,6 +94,10 @@ pub const ERR_NOTE_INVALID_TYPE: u32 = 0x00020043;
 pub const ERR_NOTE_NUM_OF_ASSETS_EXCEED_LIMIT: u32 = 0x0002002F;
 pub const ERR_NOTE_TAG_MUST_BE_U32: u32 = 0x00020045;
 
+pub const ERR_ORACLE_PRICE_ENTRY_IS_STALE: u32 = 0x00020060;
+pub const ERR_ORACLE_PRICE_MUST_BE_NON_ZERO: u32 = 0x0002005F;
+pub const ERR_ORACLE_PRICE_PAIR_NOT_FOUND: u32 = 0x00020061;
+
 pub const ERR_P2IDR_RECLAIM_ACCT_IS_NOT_SENDER: u32 = 0x00020053;
 pub const ERR_P2IDR_RECLAIM_HEIGHT_NOT_REACHED: u32 = 0x00020054;
 pub const ERR_P2IDR_WRONG_NUMBER_OF_INPUTS: u32 = 0x00020052;
@@ -115,7 +139,7 @@ pub const ERR_VAULT_NON_FUNGIBLE_ASSET_ALREADY_EXISTS: u32 = 0x0002001C;
 pub const ERR_VAULT_NON_FUNGIBLE_ASSET_TO_REMOVE_NOT_FOUND: u32 = 0x0002001F;
 pub const ERR_VAULT_REMOVE_FUNGIBLE_ASSET_FAILED_INITIAL_VALUE_INVALID: u32 = 0x0002001E;
 
-pub const TX_KERNEL_ERRORS: [(u32, &str); 90] = [
+pub const TX_KERNEL_ERRORS: [(u32, &str); 111] = [
     (ERR_ACCOUNT_ANCHOR_BLOCK_HASH_MUST_NOT_BE_EMPTY, "Anchor block hash must not be empty"),
     (ERR_ACCOUNT_CODE_COMMITMENT_MISMATCH, "Computed account code commitment does not match recorded account code commitment"),
     (ERR_ACCOUNT_CODE_IS_NOT_UPDATABLE, "Account code must be updatable for it to be possible to set new code"),
@@ -138,8 +162,21 @@ pub const TX_KERNEL_ERRORS: [(u32, &str); 90] = [
     (ERR_ACCOUNT_TOO_MANY_STORAGE_SLOTS, "Number of account storage slots exceeds the maximum limit of 255"),
     (ERR_ACCOUNT_TOTAL_ISSUANCE_PROC_CAN_ONLY_BE_CALLED_ON_FUNGIBLE_FAUCET, "The get_fungible_faucet_total_issuance procedure can only be called on a fungible faucet"),
 
+    (ERR_BLOB_STORAGE_BLOB_IS_EMPTY, "Blob must consist of at least one chunk"),
+    (ERR_BLOB_STORAGE_CHUNK_COUNT_MISMATCH, "Number of blob chunks does not match the byte length of the blob"),
+    (ERR_BLOB_STORAGE_TOO_MANY_CHUNKS, "Number of blob chunks exceeds the maximum number of chunks supported by the blob storage"),
+
     (ERR_EPILOGUE_TOTAL_NUMBER_OF_ASSETS_MUST_STAY_THE_SAME, "Total number of assets in the account and all involved notes must stay the same"),
 
+    (ERR_ESCROW_CONSUMER_IS_NOT_ARBITER, "ESCROW can only be released or split without a signature by the arbiter"),
+    (ERR_ESCROW_CONSUMER_IS_NOT_BENEFICIARY, "ESCROW can only be released with the arbiter's signature by the beneficiary"),
+    (ERR_ESCROW_CONSUMER_IS_NOT_SENDER, "ESCROW can only be refunded to the original sender"),
+    (ERR_ESCROW_INVALID_SPLIT_AMOUNT, "ESCROW split amount must be non-zero and lower than the amount of the escrowed asset"),
+    (ERR_ESCROW_REFUND_HEIGHT_NOT_REACHED, "ESCROW can not be refunded as the transaction's reference block is lower than the refund height"),
+    (ERR_ESCROW_SPLIT_REQUIRES_SINGLE_FUNGIBLE_ASSET, "ESCROW can only be split if it holds exactly one fungible asset"),
+    (ERR_ESCROW_UNKNOWN_ACTION, "ESCROW note args specify an unknown action"),
+    (ERR_ESCROW_WRONG_NUMBER_OF_INPUTS, "ESCROW script expects exactly 20 note inputs"),
+
     (ERR_FAUCET_BURN_CANNOT_EXCEED_EXISTING_TOTAL_SUPPLY, "Asset amount to burn can not exceed the existing total supply"),
     (ERR_FAUCET_BURN_NON_FUNGIBLE_ASSET_CAN_ONLY_BE_CALLED_ON_NON_FUNGIBLE_FAUCET, "The burn_non_fungible_asset procedure can only be called on a non-fungible faucet"),
     (ERR_FAUCET_INVALID_STORAGE_OFFSET, "Storage offset is invalid for a faucet account (0 is prohibited as it is the reserved data slot for faucets)"),
@@ -160,6 +197,13 @@ pub const TX_KERNEL_ERRORS: [(u32, &str); 90] = [
     (ERR_FUNGIBLE_ASSET_FORMAT_ELEMENT_TWO_AND_THREE_MUST_BE_FUNGIBLE_FAUCET_ID, "Malformed fungible asset: ASSET[2] and ASSET[3] must be a valid fungible faucet id"),
     (ERR_FUNGIBLE_ASSET_FORMAT_ELEMENT_ZERO_MUST_BE_WITHIN_LIMITS, "Malformed fungible asset: ASSET[0] exceeds the maximum allowed amount"),
     (ERR_FUNGIBLE_ASSET_PROVIDED_FAUCET_ID_IS_INVALID, "Failed to build the fungible asset because the provided faucet id is not from a fungible faucet"),
+    (ERR_FUNGIBLE_FAUCET_DISTRIBUTE_WOULD_EXCEED_RECIPIENT_MINT_CAP, "Distribute would cause the mint cap of the recipient to be exceeded"),
+    (ERR_FUNGIBLE_FAUCET_DISTRIBUTION_IS_PAUSED, "Distribution of assets by the fungible faucet is paused"),
+    (ERR_FUNGIBLE_FAUCET_MAX_SUPPLY_BELOW_TOTAL_ISSUANCE, "Maximum supply of the fungible faucet cannot be lower than its total issuance"),
+    (ERR_FUNGIBLE_FAUCET_MAX_SUPPLY_CANNOT_BE_INCREASED, "Maximum supply of the fungible faucet cannot be increased"),
+    (ERR_FUNGIBLE_FAUCET_PAUSED_FLAG_MUST_BE_BOOLEAN, "Paused flag of the fungible faucet must be either 0 or 1"),
+    (ERR_FUNGIBLE_FAUCET_RECIPIENT_IS_NOT_P2ID_FOR_CAPPED_ACCOUNT, "Recipient of the note does not match the P2ID recipient for the capped recipient account"),
+    (ERR_FUNGIBLE_FAUCET_RECIPIENT_MINT_CAP_EXCEEDS_MAX_AMOUNT, "Recipient mint cap of the fungible faucet exceeds the maximum amount of a fungible asset"),
 
     (ERR_KERNEL_PROCEDURE_OFFSET_OUT_OF_BOUNDS, "Provided kernel procedure offset is out of bounds"),
 
@@ -180,6 +224,10 @@ pub const TX_KERNEL_ERRORS: [(u32, &str); 90] = [
     (ERR_NOTE_NUM_OF_ASSETS_EXCEED_LIMIT, "Number of assets in a note exceed 255"),
     (ERR_NOTE_TAG_MUST_BE_U32, "The note's tag must fit into a u32 so the 32 most significant bits must be zero."),
 
+    (ERR_ORACLE_PRICE_ENTRY_IS_STALE, "Timestamp of a pushed price entry must be greater than the timestamp of the stored entry"),
+    (ERR_ORACLE_PRICE_MUST_BE_NON_ZERO, "Price of a price entry must be non-zero"),
+    (ERR_ORACLE_PRICE_PAIR_NOT_FOUND, "Price oracle does not have a price entry for the requested pair"),
+
     (ERR_P2IDR_RECLAIM_ACCT_IS_NOT_SENDER, "P2IDR's reclaimer is not the original sender"),
     (ERR_P2IDR_RECLAIM_HEIGHT_NOT_REACHED, "P2IDR can not be reclaimed as the transaction's reference block is lower than the reclaim height"),
     (ERR_P2IDR_WRONG_NUMBER_OF_INPUTS, "P2IDR scripts expect exactly 3 note inputs"),
@@ -4,7 +4,7 @@ use miden_objects::{
     account::AccountId,
     asset::Asset,
     block::BlockNumber,
-    crypto::rand::FeltRng,
+    crypto::{dsa::rpo_falcon512::PublicKey, rand::FeltRng},
     note::{
         Note, NoteAssets, NoteDetails, NoteExecutionHint, NoteExecutionMode, NoteInputs,
         NoteMetadata, NoteRecipient, NoteTag, NoteType,
@@ -137,3 +137,70 @@ pub fn create_swap_note<R: FeltRng>(
 
     Ok((note, payback_note))
 }
+
+/// Generates an ESCROW note - escrow of assets resolved by an arbiter - and returns the note as
+/// well as the [NoteRecipient]s of the P2ID notes the arbiter may create for the beneficiary and
+/// the sender respectively.
+///
+/// This script locks the assets of the note until one of the following spend paths is taken (see
+/// [utils::EscrowAction]):
+/// - the `beneficiary` consumes the note, provided the `arbiter` signed the release message of the
+///   note with the key corresponding to `arbiter_key`.
+/// - the `arbiter` consumes the note and forwards all assets to the `beneficiary`.
+/// - the `sender` consumes the note once the `refund_height` is reached.
+/// - the `arbiter` consumes the note and splits its single fungible asset between the `beneficiary`
+///   and the `sender`.
+///
+/// Notes created by the arbiter use the same note type as the escrow note. The passed-in `rng` is
+/// used to generate the serial numbers of the escrow note and the notes created by the arbiter.
+/// The returned note's tag is set to the beneficiary's account ID.
+///
+/// # Errors
+/// Returns an error if deserialization or compilation of the `ESCROW` script fails.
+#[allow(clippy::too_many_arguments)]
+pub fn create_escrow_note<R: FeltRng>(
+    sender: AccountId,
+    beneficiary: AccountId,
+    arbiter: AccountId,
+    arbiter_key: PublicKey,
+    assets: Vec<Asset>,
+    note_type: NoteType,
+    aux: Felt,
+    refund_height: BlockNumber,
+    rng: &mut R,
+) -> Result<(Note, NoteRecipient, NoteRecipient), NoteError> {
+    let note_script = scripts::escrow();
+
+    let beneficiary_recipient = utils::build_p2id_recipient(beneficiary, rng.draw_word())?;
+    let sender_recipient = utils::build_p2id_recipient(sender, rng.draw_word())?;
+
+    let beneficiary_tag = NoteTag::from_account_id(beneficiary, NoteExecutionMode::Local)?;
+    let sender_tag = NoteTag::from_account_id(sender, NoteExecutionMode::Local)?;
+
+    let mut inputs = vec![
+        beneficiary.suffix(),
+        beneficiary.prefix().as_felt(),
+        arbiter.suffix(),
+        arbiter.prefix().as_felt(),
+    ];
+    inputs.extend(Word::from(arbiter_key));
+    inputs.extend(Word::from(beneficiary_recipient.digest()));
+    inputs.extend(Word::from(sender_recipient.digest()));
+    inputs.extend([
+        Felt::from(refund_height),
+        Felt::from(beneficiary_tag.inner()),
+        Felt::from(sender_tag.inner()),
+        Felt::from(note_type),
+    ]);
+    let inputs = NoteInputs::new(inputs)?;
+
+    let serial_num = rng.draw_word();
+
+    let vault = NoteAssets::new(assets)?;
+    let metadata =
+        NoteMetadata::new(sender, note_type, beneficiary_tag, NoteExecutionHint::always(), aux)?;
+    let recipient = NoteRecipient::new(serial_num, note_script, inputs);
+    let note = Note::new(vault, metadata, recipient);
+
+    Ok((note, beneficiary_recipient, sender_recipient))
+}
@@ -1,7 +1,10 @@
+use alloc::format;
+
 use miden_objects::{
-    note::NoteScript,
+    note::{NoteSchemaField, NoteScript, NoteScriptSchema},
     utils::{sync::LazyLock, Deserializable},
     vm::Program,
+    Digest,
 };
 
 // Initialize the P2ID note script only once
@@ -25,17 +28,300 @@ static SWAP_SCRIPT: LazyLock<NoteScript> = LazyLock::new(|| {
     NoteScript::new(program)
 });
 
+// Initialize the ESCROW note script only once
+static ESCROW_SCRIPT: LazyLock<NoteScript> = LazyLock::new(|| {
+    let bytes = include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/ESCROW.masb"));
+    let program = Program::read_from_bytes(bytes).expect("Shipped ESCROW script is well-formed");
+    NoteScript::new(program)
+});
+
+// P2ID
+// ================================================================================================
+
 /// Returns the P2ID (Pay-to-ID) note script.
 pub fn p2id() -> NoteScript {
     P2ID_SCRIPT.clone()
 }
 
+/// Returns the schema of the note inputs and note args expected by the [P2ID](p2id) note script.
+pub fn p2id_schema() -> NoteScriptSchema {
+    NoteScriptSchema::new(
+        NoteSchemaField::account_id("target_id", "ID of the target account").into(),
+    )
+    .expect("P2ID schema declares valid inputs")
+}
+
+// P2IDR
+// ================================================================================================
+
 /// Returns the P2IDR (Pay-to-ID with recall) note script.
 pub fn p2idr() -> NoteScript {
     P2IDR_SCRIPT.clone()
 }
 
+/// Returns the schema of the note inputs and note args expected by the [P2IDR](p2idr) note
+/// script.
+pub fn p2idr_schema() -> NoteScriptSchema {
+    let mut inputs = NoteSchemaField::account_id("target_id", "ID of the target account").to_vec();
+    inputs.push(NoteSchemaField::u32(
+        "reclaim_block_height",
+        "block height from which the sender can reclaim the note",
+    ));
+
+    NoteScriptSchema::new(inputs).expect("P2IDR schema declares valid inputs")
+}
+
+// SWAP
+// ================================================================================================
+
 /// Returns the SWAP (Swap note) note script.
 pub fn swap() -> NoteScript {
     SWAP_SCRIPT.clone()
 }
+
+/// Returns the schema of the note inputs and note args expected by the [SWAP](swap) note script.
+pub fn swap_schema() -> NoteScriptSchema {
+    let mut inputs = word_fields("payback_recipient", "recipient of the payback note").to_vec();
+    inputs.extend(word_fields("requested_asset", "asset requested in exchange"));
+    inputs.extend([
+        NoteSchemaField::u32("payback_tag", "tag of the payback note"),
+        NoteSchemaField::new("payback_execution_hint", "execution hint of the payback note"),
+    ]);
+
+    NoteScriptSchema::new(inputs).expect("SWAP schema declares valid inputs")
+}
+
+// ESCROW
+// ================================================================================================
+
+/// Returns the ESCROW (Escrow with arbiter resolution) note script.
+pub fn escrow() -> NoteScript {
+    ESCROW_SCRIPT.clone()
+}
+
+/// Returns the root of the ESCROW note script.
+///
+/// The root is the same for all escrow notes and can be used to recognize them, e.g. when looking
+/// up notes by their script.
+pub fn escrow_root() -> Digest {
+    ESCROW_SCRIPT.hash()
+}
+
+/// Returns the schema of the note inputs and note args expected by the [ESCROW](escrow) note
+/// script.
+///
+/// The note args select the spend path of the note, see
+/// [`EscrowAction`](super::utils::EscrowAction).
+pub fn escrow_schema() -> NoteScriptSchema {
+    let mut inputs =
+        NoteSchemaField::account_id("beneficiary_id", "ID of the beneficiary").to_vec();
+    inputs.extend(NoteSchemaField::account_id("arbiter_id", "ID of the arbiter"));
+    inputs.extend(word_fields("arbiter_pub_key", "public key of the arbiter"));
+    inputs.extend(word_fields(
+        "beneficiary_recipient",
+        "recipient of the P2ID note created for the beneficiary",
+    ));
+    inputs.extend(word_fields(
+        "sender_recipient",
+        "recipient of the P2ID note created for the sender",
+    ));
+    inputs.extend([
+        NoteSchemaField::u32("refund_height", "block height from which the sender can be refunded"),
+        NoteSchemaField::u32("beneficiary_tag", "tag of the note created for the beneficiary"),
+        NoteSchemaField::u32("sender_tag", "tag of the note created for the sender"),
+        NoteSchemaField::u32("output_note_type", "type of the notes created by the arbiter"),
+    ]);
+
+    NoteScriptSchema::new(inputs)
+        .and_then(|schema| {
+            schema.with_arg(
+                0,
+                NoteSchemaField::new(
+                    "split_amount",
+                    "amount of the escrowed asset moved to the beneficiary by the SPLIT action",
+                ),
+            )
+        })
+        .and_then(|schema| {
+            schema.with_arg(
+                3,
+                NoteSchemaField::u32(
+                    "action",
+                    "spend path: RELEASE (0), RELEASE_BY_ARBITER (1), REFUND (2) or SPLIT (3)",
+                ),
+            )
+        })
+        .expect("ESCROW schema declares valid inputs and args")
+}
+
+// STANDARD SCHEMAS
+// ================================================================================================
+
+/// Returns the schema of the standard note script with the provided root, or `None` if the root
+/// does not belong to a standard note script.
+///
+/// This is useful when only the script root of a note is known, e.g. when checking the inputs of
+/// a note received from another party. Otherwise the schema functions next to the script
+/// constructors (e.g. [p2id_schema]) should be used.
+pub fn standard_schema(script_root: Digest) -> Option<NoteScriptSchema> {
+    if script_root == P2ID_SCRIPT.hash() {
+        Some(p2id_schema())
+    } else if script_root == P2IDR_SCRIPT.hash() {
+        Some(p2idr_schema())
+    } else if script_root == SWAP_SCRIPT.hash() {
+        Some(swap_schema())
+    } else if script_root == ESCROW_SCRIPT.hash() {
+        Some(escrow_schema())
+    } else {
+        None
+    }
+}
+
+/// Returns the fields describing the four elements of a word input.
+fn word_fields(name: &str, description: &str) -> [NoteSchemaField; 4] {
+    core::array::from_fn(|i| {
+        NoteSchemaField::new(format!("{name}[{i}]"), format!("element {i} of the {description}"))
+    })
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use miden_objects::{
+        account::AccountId,
+        asset::FungibleAsset,
+        block::BlockNumber,
+        crypto::{dsa::rpo_falcon512::PublicKey, rand::RpoRandomCoin},
+        note::{NoteInputs, NoteType},
+        testing::account_id::{
+            ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2,
+            ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN, ACCOUNT_ID_SENDER,
+        },
+        Felt, NoteScriptSchemaError, EMPTY_WORD, ONE, ZERO,
+    };
+
+    use super::*;
+    use crate::note::{
+        create_escrow_note, create_p2id_note, create_swap_note, utils::EscrowAction,
+    };
+
+    #[test]
+    fn standard_notes_conform_to_schemas() {
+        let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+        let target =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+        let offered_asset = FungibleAsset::new(
+            AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap(),
+            10,
+        )
+        .unwrap();
+        let requested_asset = FungibleAsset::new(
+            AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2).unwrap(),
+            20,
+        )
+        .unwrap();
+        let mut rng = RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]);
+
+        let p2id = create_p2id_note(
+            sender,
+            target,
+            vec![offered_asset.into()],
+            NoteType::Public,
+            ZERO,
+            &mut rng,
+        )
+        .unwrap();
+        let schema = standard_schema(p2id.script().hash()).unwrap();
+        assert_eq!(schema, p2id_schema());
+        schema.check(p2id.inputs(), &EMPTY_WORD).unwrap();
+
+        let (swap, _) = create_swap_note(
+            sender,
+            offered_asset.into(),
+            requested_asset.into(),
+            NoteType::Public,
+            ZERO,
+            &mut rng,
+        )
+        .unwrap();
+        let schema = standard_schema(swap.script().hash()).unwrap();
+        assert_eq!(schema, swap_schema());
+        schema.check(swap.inputs(), &EMPTY_WORD).unwrap();
+
+        // the P2ID inputs do not match the layout expected by the SWAP script
+        assert!(matches!(
+            swap_schema().check(p2id.inputs(), &EMPTY_WORD),
+            Err(NoteScriptSchemaError::InputsCountMismatch { expected: 10, actual: 2 })
+        ));
+    }
+
+    #[test]
+    fn escrow_schema_accepts_escrow_actions() {
+        let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+        let beneficiary =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+        let asset = FungibleAsset::new(
+            AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap(),
+            10,
+        )
+        .unwrap();
+        let (escrow, ..) = create_escrow_note(
+            sender,
+            beneficiary,
+            sender,
+            PublicKey::new([ONE; 4]),
+            vec![asset.into()],
+            NoteType::Public,
+            ZERO,
+            BlockNumber::from(100),
+            &mut RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]),
+        )
+        .unwrap();
+
+        let schema = escrow_schema();
+        assert_eq!(standard_schema(escrow_root()), Some(schema.clone()));
+        assert_eq!(schema.num_inputs(), 20);
+
+        for action in [
+            EscrowAction::Release,
+            EscrowAction::ReleaseByArbiter,
+            EscrowAction::Refund,
+            EscrowAction::Split { beneficiary_amount: 5 },
+        ] {
+            schema.check(escrow.inputs(), &action.note_args()).unwrap();
+        }
+
+        assert!(matches!(
+            schema.check(escrow.inputs(), &[ZERO, Felt::new(1), ZERO, ZERO]),
+            Err(NoteScriptSchemaError::UndeclaredArg { index: 1, .. })
+        ));
+    }
+
+    #[test]
+    fn schemas_reject_malformed_inputs() {
+        let target =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+
+        // the least significant byte of the suffix of an account ID must be zero
+        let invalid_suffix = Felt::new(target.suffix().as_int() + 1);
+        let inputs = NoteInputs::new(vec![invalid_suffix, target.prefix().as_felt()]).unwrap();
+        assert!(matches!(
+            p2id_schema().check(&inputs, &EMPTY_WORD),
+            Err(NoteScriptSchemaError::InvalidAccountIdInputs { index: 0, .. })
+        ));
+
+        // the reclaim block height does not fit into a u32
+        let inputs = NoteInputs::new(vec![
+            target.suffix(),
+            target.prefix().as_felt(),
+            Felt::new(u32::MAX as u64 + 1),
+        ])
+        .unwrap();
+        assert!(matches!(
+            p2idr_schema().check(&inputs, &EMPTY_WORD),
+            Err(NoteScriptSchemaError::InputNotU32 { index: 2, .. })
+        ));
+    }
+}
@@ -1,8 +1,11 @@
+use alloc::vec::Vec;
+
 use miden_objects::{
     account::AccountId,
     asset::Asset,
+    crypto::dsa::rpo_falcon512::PublicKey,
     note::{NoteExecutionMode, NoteInputs, NoteRecipient, NoteTag, NoteType},
-    NoteError, Word,
+    Digest, Felt, Hasher, NoteError, Word, ZERO,
 };
 
 use crate::note::scripts;
@@ -52,6 +55,64 @@ pub fn build_swap_tag(
     }
 }
 
+/// The spend paths of an escrow note created via [`create_escrow_note`](super::create_escrow_note).
+///
+/// The spend path is selected by providing [`EscrowAction::note_args`] as the note args of the
+/// escrow note when consuming it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EscrowAction {
+    /// The beneficiary consumes the note and receives all of its assets.
+    ///
+    /// Requires the arbiter's signature over the release message of the note (see
+    /// [`build_escrow_release_message`]), provided via the advice map as returned by
+    /// [`build_escrow_signature_advice_entry`].
+    Release,
+    /// The arbiter consumes the note and moves all of its assets into a P2ID note for the
+    /// beneficiary.
+    ReleaseByArbiter,
+    /// The sender consumes the note and receives all of its assets. Only possible once the refund
+    /// height of the note is reached.
+    Refund,
+    /// The arbiter consumes the note and splits its single fungible asset: `beneficiary_amount` is
+    /// moved into a P2ID note for the beneficiary and the remainder into a P2ID note for the
+    /// sender.
+    Split { beneficiary_amount: u64 },
+}
+
+impl EscrowAction {
+    /// Returns the note args selecting this spend path.
+    pub fn note_args(&self) -> Word {
+        match self {
+            EscrowAction::Release => [ZERO, ZERO, ZERO, Felt::new(0)],
+            EscrowAction::ReleaseByArbiter => [ZERO, ZERO, ZERO, Felt::new(1)],
+            EscrowAction::Refund => [ZERO, ZERO, ZERO, Felt::new(2)],
+            EscrowAction::Split { beneficiary_amount } => {
+                [Felt::new(*beneficiary_amount), ZERO, ZERO, Felt::new(3)]
+            },
+        }
+    }
+}
+
+/// Returns the message the arbiter of an escrow note has to sign to approve the release of the
+/// note's assets to the beneficiary, i.e. `hash(serial_num, escrow_script_root)`.
+pub fn build_escrow_release_message(serial_num: Word) -> Word {
+    Hasher::merge(&[serial_num.into(), scripts::escrow_root()]).into()
+}
+
+/// Returns the advice map entry providing the arbiter's signature over the release message of the
+/// escrow note with the specified serial number.
+///
+/// `signature` is the signature encoded as expected on the advice stack by
+/// `std::crypto::dsa::rpo_falcon512::verify`.
+pub fn build_escrow_signature_advice_entry(
+    arbiter_key: PublicKey,
+    serial_num: Word,
+    signature: Vec<Felt>,
+) -> (Digest, Vec<Felt>) {
+    let message = build_escrow_release_message(serial_num);
+    (Hasher::merge(&[Word::from(arbiter_key).into(), message.into()]), signature)
+}
+
 #[cfg(test)]
 mod tests {
     use miden_objects::{
@@ -0,0 +1,158 @@
+use alloc::{collections::BTreeMap, sync::Arc, vec::Vec};
+
+use miden_objects::assembly::{
+    mast::{MastForest, MastNode, MastNodeId},
+    Assembler, DefaultSourceManager, Library, Report,
+};
+use miden_stdlib::StdLibrary;
+
+use super::TransactionKernel;
+use crate::MidenLib;
+
+// TRANSACTION ASSEMBLER BUILDER
+// ================================================================================================
+
+/// A builder for an [Assembler] instantiated with the transaction kernel and loaded with the Miden
+/// stdlib, miden-lib and any number of additional libraries.
+///
+/// This allows applications which compile account components, note scripts and transaction
+/// scripts against their own shared libraries to do so without reconstructing the assembler
+/// pipeline of the transaction kernel.
+#[derive(Clone)]
+pub struct TransactionAssemblerBuilder {
+    libraries: Vec<Library>,
+    stdlib_debug_decorators: bool,
+}
+
+impl TransactionAssemblerBuilder {
+    /// Returns a new [TransactionAssemblerBuilder] without any additional libraries which keeps
+    /// the debug decorators of the Miden stdlib.
+    pub fn new() -> Self {
+        Self {
+            libraries: Vec::new(),
+            stdlib_debug_decorators: true,
+        }
+    }
+
+    /// Adds the provided library to the assembler.
+    pub fn with_library(mut self, library: Library) -> Self {
+        self.libraries.push(library);
+        self
+    }
+
+    /// Adds the provided libraries to the assembler.
+    pub fn with_libraries(mut self, libraries: impl IntoIterator<Item = Library>) -> Self {
+        self.libraries.extend(libraries);
+        self
+    }
+
+    /// Sets whether the debug decorators of the Miden stdlib are retained.
+    ///
+    /// Decorators do not affect the MAST roots of the stdlib procedures, so disabling them does
+    /// not change the code compiled by the assembler. It only reduces the size of the MAST forests
+    /// which include stdlib procedures. This has an effect only if miden-lib is built with the
+    /// `with-debug-info` feature.
+    pub fn with_stdlib_debug_decorators(mut self, enabled: bool) -> Self {
+        self.stdlib_debug_decorators = enabled;
+        self
+    }
+
+    /// Returns a new [Assembler] instantiated with the transaction kernel and loaded with the
+    /// Miden stdlib, miden-lib and the libraries added to this builder.
+    ///
+    /// # Errors
+    /// Returns an error if any of the libraries added to this builder cannot be added to the
+    /// assembler, e.g. because it conflicts with a library which was already added.
+    pub fn build(self) -> Result<Assembler, Report> {
+        let stdlib: Library = StdLibrary::default().into();
+        let stdlib = if self.stdlib_debug_decorators {
+            stdlib
+        } else {
+            strip_decorators(&stdlib)
+        };
+
+        let source_manager = Arc::new(DefaultSourceManager::default());
+        let mut assembler = Assembler::with_kernel(source_manager, TransactionKernel::kernel())
+            .with_library(stdlib)
+            .expect("failed to load std-lib")
+            .with_library(MidenLib::default())
+            .expect("failed to load miden-lib");
+
+        for library in self.libraries {
+            assembler.add_library(library)?;
+        }
+
+        Ok(assembler)
+    }
+}
+
+impl Default for TransactionAssemblerBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns a copy of the provided library with all decorators removed from its MAST forest.
+fn strip_decorators(library: &Library) -> Library {
+    let mut mast_forest = MastForest::clone(library.mast_forest());
+
+    for node_idx in 0..mast_forest.num_nodes() {
+        let node_id = MastNodeId::from_u32_safe(node_idx, &mast_forest)
+            .expect("node index should be within the bounds of the MAST forest");
+
+        match &mut mast_forest[node_id] {
+            MastNode::Block(basic_block) => basic_block.set_decorators(Vec::new()),
+            node => {
+                node.set_before_enter(Vec::new());
+                node.set_after_exit(Vec::new());
+            },
+        }
+    }
+
+    let exports: BTreeMap<_, _> = library
+        .exports()
+        .map(|name| (name.clone(), library.get_export_node_id(name)))
+        .collect();
+
+    Library::new(Arc::new(mast_forest), exports)
+        .expect("stripping decorators should not invalidate the library exports")
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strip_stdlib_decorators() {
+        let stdlib: Library = StdLibrary::default().into();
+        let stripped = strip_decorators(&stdlib);
+
+        assert_eq!(stripped.digest(), stdlib.digest());
+        assert!(stripped.mast_forest().nodes().iter().all(|node| match node {
+            MastNode::Block(basic_block) => basic_block.decorators().is_empty(),
+            node => node.before_enter().is_empty() && node.after_exit().is_empty(),
+        }));
+
+        let program = "
+            use.std::math::u64
+
+            begin
+                push.1.0 push.2.0 exec.u64::wrapping_add dropw
+            end
+        ";
+        let assembler = TransactionAssemblerBuilder::new()
+            .with_stdlib_debug_decorators(false)
+            .build()
+            .unwrap();
+        assert_eq!(
+            assembler.assemble_program(program).unwrap().hash(),
+            TransactionKernel::assembler().assemble_program(program).unwrap().hash()
+        );
+    }
+}
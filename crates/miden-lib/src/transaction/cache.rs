@@ -0,0 +1,169 @@
+use alloc::vec::Vec;
+
+use miden_objects::{
+    account::{Account, AccountId, StorageSlot},
+    block::BlockNumber,
+    transaction::TransactionInputs,
+    vm::AdviceInputs,
+    Digest,
+};
+
+use super::inputs::{
+    add_account_state_to_advice_inputs, add_chain_mmr_peaks_to_advice_inputs,
+    add_code_to_advice_inputs, add_kernel_hashes_to_advice_inputs,
+    add_storage_map_to_advice_inputs, add_vault_to_advice_inputs,
+};
+
+// EXECUTION CONTEXT CACHE
+// ================================================================================================
+
+/// A cache of the advice inputs shared by sequential transactions executed against the same
+/// account and the same reference block.
+///
+/// The cache keeps the advice map and Merkle store data of the transaction context in two
+/// cumulative layers:
+/// - The storage layer holds the kernel data, the chain MMR peaks, the account code and the account
+///   storage maps. It is rebuilt when the account code or any of the storage maps changes.
+/// - The account layer extends the storage layer with the account vault. It is rebuilt from a copy
+///   of the storage layer when the vault changes.
+///
+/// When preparing the inputs of a transaction via [TransactionKernel::prepare_inputs_with_cache]
+/// without initial advice inputs, the advice inputs are seeded with a copy of the account layer
+/// instead of inserting its Merkle nodes and advice map entries one by one. Only the
+/// authentication paths of the chain MMR and the account state entry (i.e., the storage slots and
+/// the optional account seed) are added on top, since they differ between transactions.
+///
+/// The cache is cleared when the account, the reference block hash or the chain length differs
+/// from the ones of the previous transaction. The advice inputs prepared with a cache contain the
+/// same data as the ones prepared without it, i.e., the kernel, chain MMR and account data replaces
+/// colliding advice map entries of the initial advice inputs in both cases.
+///
+/// [TransactionKernel::prepare_inputs_with_cache]: super::TransactionKernel::prepare_inputs_with_cache
+#[derive(Debug, Clone, Default)]
+pub struct ExecutionContextCache {
+    /// The account, the reference block hash and the chain length for which the cached data is
+    /// valid.
+    context: Option<(AccountId, Digest, BlockNumber)>,
+    /// The commitments of the account code and storage maps the storage layer was built from.
+    storage_key: Option<Vec<Digest>>,
+    /// The kernel, chain MMR peaks, account code and storage map data.
+    storage_inputs: AdviceInputs,
+    /// The commitment of the account vault the account layer was built from.
+    vault_commitment: Option<Digest>,
+    /// The storage layer extended with the account vault data.
+    account_inputs: AdviceInputs,
+}
+
+impl ExecutionContextCache {
+    /// Returns a new empty [ExecutionContextCache].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the ID of the account for which data is cached, or `None` if the cache is empty.
+    pub fn account_id(&self) -> Option<AccountId> {
+        self.context.map(|(account_id, ..)| account_id)
+    }
+
+    /// Returns the hash of the reference block for which data is cached, or `None` if the cache is
+    /// empty.
+    pub fn block_hash(&self) -> Option<Digest> {
+        self.context.map(|(_, block_hash, _)| block_hash)
+    }
+
+    /// Returns the length of the chain for which data is cached, or `None` if the cache is empty.
+    pub fn chain_length(&self) -> Option<BlockNumber> {
+        self.context.map(|(.., chain_length)| chain_length)
+    }
+
+    /// Returns the commitment of the account vault for which data is cached, or `None` if no
+    /// vault data is cached.
+    pub fn vault_commitment(&self) -> Option<Digest> {
+        self.vault_commitment
+    }
+
+    // STATE MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Removes all data from this cache.
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Extends the provided advice inputs with the kernel, chain MMR and account data of the
+    /// provided transaction inputs, reusing the cached data where possible.
+    pub(super) fn extend_advice_inputs(
+        &mut self,
+        tx_inputs: &TransactionInputs,
+        kernel_version: u8,
+        advice_inputs: &mut AdviceInputs,
+    ) {
+        let account = tx_inputs.account();
+        let block_chain = tx_inputs.block_chain();
+        let context = (account.id(), tx_inputs.block_header().hash(), block_chain.chain_length());
+
+        if self.context != Some(context) {
+            self.clear();
+            self.context = Some(context);
+        }
+
+        // --- kernel, chain MMR peaks, account code and storage maps -------------
+        let storage_key = storage_key(account);
+        if self.storage_key.as_ref() != Some(&storage_key) {
+            let mut storage_inputs = AdviceInputs::default();
+            add_kernel_hashes_to_advice_inputs(&mut storage_inputs, kernel_version);
+            add_chain_mmr_peaks_to_advice_inputs(block_chain, &mut storage_inputs);
+            for slot in account.storage().slots() {
+                if let StorageSlot::Map(map) = slot {
+                    add_storage_map_to_advice_inputs(map, &mut storage_inputs);
+                }
+            }
+            add_code_to_advice_inputs(account.code(), &mut storage_inputs);
+
+            self.storage_key = Some(storage_key);
+            self.storage_inputs = storage_inputs;
+            self.vault_commitment = None;
+        }
+
+        // --- account vault ------------------------------------------------------
+        let vault_commitment = account.vault().commitment();
+        if self.vault_commitment != Some(vault_commitment) {
+            let mut account_inputs = self.storage_inputs.clone();
+            add_vault_to_advice_inputs(account.vault(), &mut account_inputs);
+
+            self.vault_commitment = Some(vault_commitment);
+            self.account_inputs = account_inputs;
+        }
+
+        // seed the advice inputs with the cached data if they only hold the advice stack.
+        // Otherwise, the cached data is added on top of the initial advice inputs, so that it
+        // replaces colliding advice map entries as when preparing the inputs without a cache.
+        let stack = advice_inputs.stack().to_vec();
+        if *advice_inputs == AdviceInputs::default().with_stack(stack.iter().copied()) {
+            *advice_inputs = self.account_inputs.clone().with_stack(stack);
+        } else {
+            advice_inputs.extend(self.account_inputs.clone());
+        }
+
+        // --- chain MMR authentication paths and account state ---------------------
+        advice_inputs.extend_merkle_store(block_chain.inner_nodes());
+        add_account_state_to_advice_inputs(account, tx_inputs.account_seed(), advice_inputs);
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the commitments of the account code and the account storage maps, which identify the
+/// data of the storage layer.
+fn storage_key(account: &Account) -> Vec<Digest> {
+    let map_roots = account.storage().slots().iter().filter_map(|slot| match slot {
+        StorageSlot::Map(map) => Some(map.root()),
+        StorageSlot::Value(_) => None,
+    });
+
+    core::iter::once(account.code().commitment()).chain(map_roots).collect()
+}
@@ -31,6 +31,8 @@ const NOTE_AFTER_CREATED: u32 = 0x2_000c; // 131084
 const NOTE_BEFORE_ADD_ASSET: u32 = 0x2_000d; // 131085
 const NOTE_AFTER_ADD_ASSET: u32 = 0x2_000e; // 131086
 
+const LOG_EMITTED: u32 = 0x2_000f; // 131087
+
 /// Events which may be emitted by a transaction kernel.
 ///
 /// The events are emitted via the `emit.<event_id>` instruction. The event ID is a 32-bit
@@ -63,6 +65,8 @@ pub enum TransactionEvent {
 
     NoteBeforeAddAsset = NOTE_BEFORE_ADD_ASSET,
     NoteAfterAddAsset = NOTE_AFTER_ADD_ASSET,
+
+    LogEmitted = LOG_EMITTED,
 }
 
 impl TransactionEvent {
@@ -114,6 +118,8 @@ impl TryFrom<u32> for TransactionEvent {
             NOTE_BEFORE_ADD_ASSET => Ok(TransactionEvent::NoteBeforeAddAsset),
             NOTE_AFTER_ADD_ASSET => Ok(TransactionEvent::NoteAfterAddAsset),
 
+            LOG_EMITTED => Ok(TransactionEvent::LogEmitted),
+
             _ => Err(TransactionEventError::InvalidTransactionEvent(value)),
         }
     }
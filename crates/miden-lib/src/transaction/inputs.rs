@@ -1,13 +1,14 @@
 use alloc::vec::Vec;
 
 use miden_objects::{
-    account::{Account, StorageSlot},
+    account::{Account, AccountCode, StorageMap, StorageSlot},
+    asset::AssetVault,
     transaction::{ChainMmr, InputNote, TransactionArgs, TransactionInputs, TransactionScript},
     vm::AdviceInputs,
     Digest, Felt, FieldElement, Word, EMPTY_WORD, WORD_SIZE, ZERO,
 };
 
-use super::TransactionKernel;
+use super::{ExecutionContextCache, TransactionKernel};
 
 // ADVICE INPUTS
 // ================================================================================================
@@ -18,10 +19,14 @@ use super::TransactionKernel;
 /// This includes the initial account, an optional account seed (required for new accounts), and
 /// the input note data, including core note data + authentication paths all the way to the root
 /// of one of chain MMR peaks.
+///
+/// If a cache is provided, the kernel, chain MMR and account data is taken from the cache where
+/// possible, and the cache is updated with the data of the provided transaction inputs.
 pub(super) fn extend_advice_inputs(
     tx_inputs: &TransactionInputs,
     tx_args: &TransactionArgs,
     advice_inputs: &mut AdviceInputs,
+    cache: Option<&mut ExecutionContextCache>,
 ) {
     // TODO: remove this value and use a user input instead
     let kernel_version = 0;
@@ -29,9 +34,18 @@ pub(super) fn extend_advice_inputs(
     build_advice_stack(tx_inputs, tx_args.tx_script(), advice_inputs, kernel_version);
 
     // build the advice map and Merkle store for relevant components
-    add_kernel_hashes_to_advice_inputs(advice_inputs, kernel_version);
-    add_chain_mmr_to_advice_inputs(tx_inputs.block_chain(), advice_inputs);
-    add_account_to_advice_inputs(tx_inputs.account(), tx_inputs.account_seed(), advice_inputs);
+    match cache {
+        Some(cache) => cache.extend_advice_inputs(tx_inputs, kernel_version, advice_inputs),
+        None => {
+            add_kernel_hashes_to_advice_inputs(advice_inputs, kernel_version);
+            add_chain_mmr_to_advice_inputs(tx_inputs.block_chain(), advice_inputs);
+            add_account_to_advice_inputs(
+                tx_inputs.account(),
+                tx_inputs.account_seed(),
+                advice_inputs,
+            );
+        },
+    }
     add_input_notes_to_advice_inputs(tx_inputs, tx_args, advice_inputs);
     advice_inputs.extend(tx_args.advice_inputs().clone());
 }
@@ -126,12 +140,21 @@ fn build_advice_stack(
 /// - MMR_ROOT, is the sequential hash of the padded MMR peaks
 /// - num_blocks, is the number of blocks in the MMR.
 /// - PEAK_1 .. PEAK_N, are the MMR peaks.
-fn add_chain_mmr_to_advice_inputs(mmr: &ChainMmr, inputs: &mut AdviceInputs) {
+pub(super) fn add_chain_mmr_to_advice_inputs(mmr: &ChainMmr, inputs: &mut AdviceInputs) {
     // NOTE: keep this code in sync with the `process_chain_data` kernel procedure
 
     // add authentication paths from the MMR to the Merkle store
     inputs.extend_merkle_store(mmr.inner_nodes());
 
+    add_chain_mmr_peaks_to_advice_inputs(mmr, inputs);
+}
+
+/// Inserts the chain MMR peaks info into the advice map of the provided advice inputs.
+///
+/// The peaks are the same for all chain MMRs of a given reference block, while the authentication
+/// paths inserted by [add_chain_mmr_to_advice_inputs] depend on the blocks tracked by the chain
+/// MMR.
+pub(super) fn add_chain_mmr_peaks_to_advice_inputs(mmr: &ChainMmr, inputs: &mut AdviceInputs) {
     // insert MMR peaks info into the advice map
     let peaks = mmr.peaks();
     let mut elements = vec![Felt::new(peaks.num_leaves() as u64), ZERO, ZERO, ZERO];
@@ -160,36 +183,59 @@ fn add_account_to_advice_inputs(
     inputs: &mut AdviceInputs,
 ) {
     // --- account storage ----------------------------------------------------
-    let storage = account.storage();
-
-    for slot in storage.slots() {
+    for slot in account.storage().slots() {
         // if there are storage maps, we populate the merkle store and advice map
         if let StorageSlot::Map(map) = slot {
-            // extend the merkle store and map with the storage maps
-            inputs.extend_merkle_store(map.inner_nodes());
-            // populate advice map with Sparse Merkle Tree leaf nodes
-            inputs.extend_map(map.leaves().map(|(_, leaf)| (leaf.hash(), leaf.to_elements())));
+            add_storage_map_to_advice_inputs(map, inputs);
         }
     }
 
-    // extend advice map with storage commitment |-> length, storage slots and types vector
-    inputs.extend_map([(storage.commitment(), storage.as_elements())]);
-
     // --- account vault ------------------------------------------------------
-    let vault = account.vault();
+    add_vault_to_advice_inputs(account.vault(), inputs);
+
+    // --- account code -------------------------------------------------------
+    add_code_to_advice_inputs(account.code(), inputs);
 
+    // --- account state ------------------------------------------------------
+    add_account_state_to_advice_inputs(account, account_seed, inputs);
+}
+
+/// Inserts the Merkle nodes of the provided storage map into the Merkle store and its leaves into
+/// the advice map.
+pub(super) fn add_storage_map_to_advice_inputs(map: &StorageMap, inputs: &mut AdviceInputs) {
+    // extend the merkle store and map with the storage maps
+    inputs.extend_merkle_store(map.inner_nodes());
+    // populate advice map with Sparse Merkle Tree leaf nodes
+    inputs.extend_map(map.leaves().map(|(_, leaf)| (leaf.hash(), leaf.to_elements())));
+}
+
+/// Inserts the Merkle nodes of the provided asset vault into the Merkle store and its leaves into
+/// the advice map.
+pub(super) fn add_vault_to_advice_inputs(vault: &AssetVault, inputs: &mut AdviceInputs) {
     // extend the merkle store with account vault data
     inputs.extend_merkle_store(vault.asset_tree().inner_nodes());
 
     // populate advice map with Sparse Merkle Tree leaf nodes
     inputs
         .extend_map(vault.asset_tree().leaves().map(|(_, leaf)| (leaf.hash(), leaf.to_elements())));
+}
 
-    // --- account code -------------------------------------------------------
-    let code = account.code();
-
+/// Inserts the account code commitment |-> procedures vector entry into the advice map.
+pub(super) fn add_code_to_advice_inputs(code: &AccountCode, inputs: &mut AdviceInputs) {
     // extend the advice map with the account code data
     inputs.extend_map([(code.commitment(), code.as_elements())]);
+}
+
+/// Inserts the account storage commitment |-> storage slots and types vector entry and, if
+/// provided, the account seed into the advice map.
+pub(super) fn add_account_state_to_advice_inputs(
+    account: &Account,
+    account_seed: Option<Word>,
+    inputs: &mut AdviceInputs,
+) {
+    // extend advice map with storage commitment |-> length, storage slots and types vector
+    let storage = account.storage();
+    inputs.extend_map([(storage.commitment(), storage.as_elements())]);
 
     // --- account seed -------------------------------------------------------
     if let Some(account_seed) = account_seed {
@@ -16,11 +16,11 @@ pub type StorageSlot = u8;
 //
 // | Section           | Start address, pointer (word pointer) | End address, pointer (word pointer) | Comment                                     |
 // | ----------------- | :-----------------------------------: | :---------------------------------: | ------------------------------------------- |
-// | Bookkeeping       | 0 (0)                                 | 31 (7)                              |                                             |
+// | Bookkeeping       | 0 (0)                                 | 35 (8)                              |                                             |
 // | Global inputs     | 400 (100)                             | 423 (105)                           |                                             |
 // | Block header      | 800 (200)                             | 835 (208)                           |                                             |
 // | Chain MMR         | 1_200 (300)                           | 1_331? (332?)                       |                                             |
-// | Kernel data       | 1_600 (400)                           | 1_739 (434)                         | 34 procedures in total, 4 elements each     |
+// | Kernel data       | 1_600 (400)                           | 1_751 (437)                         | 38 procedures in total, 4 elements each     |
 // | Accounts data     | 8_192 (2048)                          | 532_479 (133_119)                   | 64 foreign accounts max, 8192 elements each |
 // | Input notes       | 4_194_304 (1_048_576)                 | ?                                   |                                             |
 // | Output notes      | 16_777_216 (4_194_304)                | ?                                   |                                             |
@@ -80,6 +80,10 @@ pub const NEW_CODE_ROOT_PTR: MemoryAddress = 24;
 /// The memory address at which the transaction expiration block number is stored.
 pub const TX_EXPIRATION_BLOCK_NUM_PTR: MemoryAddress = 28;
 
+/// The memory address at which the commitment to the log entries emitted by the transaction is
+/// stored.
+pub const TX_LOGS_COMMITMENT_PTR: MemoryAddress = 32;
+
 // GLOBAL INPUTS
 // ------------------------------------------------------------------------------------------------
 
@@ -1,8 +1,8 @@
-use alloc::{string::ToString, sync::Arc, vec::Vec};
+use alloc::{string::ToString, vec::Vec};
 
 use miden_objects::{
-    account::{AccountCode, AccountHeader, AccountId, AccountStorageHeader},
-    assembly::{Assembler, DefaultSourceManager, KernelLibrary},
+    account::{AccountCode, AccountHeader, AccountId, AccountStorageHeader, StorageMapWitness},
+    assembly::{Assembler, KernelLibrary, Library, Report},
     block::BlockNumber,
     crypto::merkle::{MerkleError, MerklePath},
     transaction::{
@@ -10,15 +10,18 @@ use miden_objects::{
     },
     utils::serde::Deserializable,
     vm::{AdviceInputs, AdviceMap, Program, ProgramInfo, StackInputs, StackOutputs},
-    Digest, Felt, TransactionOutputError, EMPTY_WORD, ZERO,
+    Digest, Felt, TransactionOutputError, EMPTY_WORD, WORD_SIZE, ZERO,
 };
-use miden_stdlib::StdLibrary;
-use outputs::EXPIRATION_BLOCK_ELEMENT_IDX;
-
-use super::MidenLib;
+use outputs::{EXPIRATION_BLOCK_ELEMENT_IDX, LOGS_COMMITMENT_WORD_IDX};
 
 pub mod memory;
 
+mod assembler;
+pub use assembler::TransactionAssemblerBuilder;
+
+mod cache;
+pub use cache::ExecutionContextCache;
+
 mod events;
 pub use events::{TransactionEvent, TransactionTrace};
 
@@ -26,7 +29,8 @@ mod inputs;
 
 mod outputs;
 pub use outputs::{
-    parse_final_account_header, FINAL_ACCOUNT_HASH_WORD_IDX, OUTPUT_NOTES_COMMITMENT_WORD_IDX,
+    parse_final_account_header, TxKernelOutputs, FINAL_ACCOUNT_HASH_WORD_IDX,
+    OUTPUT_NOTES_COMMITMENT_WORD_IDX,
 };
 
 mod errors;
@@ -102,7 +106,38 @@ impl TransactionKernel {
         );
 
         let mut advice_inputs = init_advice_inputs.unwrap_or_default();
-        inputs::extend_advice_inputs(tx_inputs, tx_args, &mut advice_inputs);
+        inputs::extend_advice_inputs(tx_inputs, tx_args, &mut advice_inputs, None);
+
+        (stack_inputs, advice_inputs)
+    }
+
+    /// Transforms the provided [TransactionInputs] and [TransactionArgs] into stack and advice
+    /// inputs needed to execute a transaction kernel for a specific transaction, reusing the data
+    /// of the provided [ExecutionContextCache] where possible.
+    ///
+    /// The returned inputs contain the same data as the ones returned by [Self::prepare_inputs].
+    /// The cache is updated with the data of the provided transaction inputs, such that preparing
+    /// the inputs of the next transaction against the same account and reference block only needs
+    /// to rebuild the parts of the account state which changed.
+    ///
+    /// If `init_advice_inputs` is provided, they will be included in the returned advice inputs.
+    pub fn prepare_inputs_with_cache(
+        tx_inputs: &TransactionInputs,
+        tx_args: &TransactionArgs,
+        init_advice_inputs: Option<AdviceInputs>,
+        cache: &mut ExecutionContextCache,
+    ) -> (StackInputs, AdviceInputs) {
+        let account = tx_inputs.account();
+
+        let stack_inputs = TransactionKernel::build_input_stack(
+            account.id(),
+            account.init_hash(),
+            tx_inputs.input_notes().commitment(),
+            tx_inputs.block_header().hash(),
+        );
+
+        let mut advice_inputs = init_advice_inputs.unwrap_or_default();
+        inputs::extend_advice_inputs(tx_inputs, tx_args, &mut advice_inputs, Some(cache));
 
         (stack_inputs, advice_inputs)
     }
@@ -113,12 +148,22 @@ impl TransactionKernel {
     /// Returns a new Miden assembler instantiated with the transaction kernel and loaded with the
     /// Miden stdlib as well as with miden-lib.
     pub fn assembler() -> Assembler {
-        let source_manager = Arc::new(DefaultSourceManager::default());
-        Assembler::with_kernel(source_manager, Self::kernel())
-            .with_library(StdLibrary::default())
-            .expect("failed to load std-lib")
-            .with_library(MidenLib::default())
-            .expect("failed to load miden-lib")
+        TransactionAssemblerBuilder::new()
+            .build()
+            .expect("assembler without additional libraries should be valid")
+    }
+
+    /// Returns a new Miden assembler instantiated with the transaction kernel and loaded with the
+    /// Miden stdlib, miden-lib and the provided libraries.
+    ///
+    /// Use [TransactionAssemblerBuilder] for more control over the construction of the assembler.
+    ///
+    /// # Errors
+    /// Returns an error if any of the provided libraries cannot be added to the assembler.
+    pub fn assembler_with_libraries(
+        libraries: impl IntoIterator<Item = Library>,
+    ) -> Result<Assembler, Report> {
+        TransactionAssemblerBuilder::new().with_libraries(libraries).build()
     }
 
     // STACK INPUTS / OUTPUTS
@@ -201,14 +246,37 @@ impl TransactionKernel {
         Ok(())
     }
 
+    /// Extends the advice inputs with the data required to read the keys of the provided storage
+    /// map witnesses.
+    ///
+    /// This allows reading specific keys of the storage maps of a foreign account without
+    /// providing the entire maps. For each witness, the advice inputs are extended with:
+    /// - the inner nodes of the authentication path of the key's leaf, which are added to the
+    ///   Merkle store.
+    /// - LEAF_HASH -> [LEAF_ELEMENTS], which is added to the advice map if the leaf is not empty.
+    ///
+    /// The witnesses can be obtained via
+    /// [AccountStorage::open_map_items](miden_objects::account::AccountStorage::open_map_items) or
+    /// [StorageMap::open](miden_objects::account::StorageMap::open).
+    pub fn extend_advice_inputs_for_storage_map_witnesses<'a>(
+        advice_inputs: &mut AdviceInputs,
+        witnesses: impl IntoIterator<Item = &'a StorageMapWitness>,
+    ) {
+        for witness in witnesses {
+            advice_inputs.extend_merkle_store(witness.inner_nodes());
+            advice_inputs.extend_map(witness.leaf_entry());
+        }
+    }
+
     /// Builds the stack for expected transaction execution outputs.
     /// The transaction kernel's output stack is formed like so:
     ///
     /// ```text
     /// [
-    ///     expiration_block_num,
     ///     OUTPUT_NOTES_COMMITMENT,
     ///     FINAL_ACCOUNT_HASH,
+    ///     expiration_block_num, 0, 0, 0,
+    ///     LOGS_COMMITMENT,
     /// ]
     /// ```
     ///
@@ -216,12 +284,17 @@ impl TransactionKernel {
     /// - OUTPUT_NOTES_COMMITMENT is a commitment to the output notes.
     /// - FINAL_ACCOUNT_HASH is a hash of the account's final state.
     /// - expiration_block_num is the block number at which the transaction will expire.
+    /// - LOGS_COMMITMENT is a commitment to the log entries emitted by the transaction, or an
+    ///   EMPTY_WORD if no log entries were emitted.
     pub fn build_output_stack(
         final_acct_hash: Digest,
         output_notes_hash: Digest,
         expiration_block_num: BlockNumber,
+        logs_commitment: Option<Digest>,
     ) -> StackOutputs {
-        let mut outputs: Vec<Felt> = Vec::with_capacity(9);
+        let mut outputs: Vec<Felt> = Vec::with_capacity(16);
+        outputs.extend(logs_commitment.unwrap_or_default());
+        outputs.extend([ZERO; 3]);
         outputs.push(Felt::from(expiration_block_num));
         outputs.extend(final_acct_hash);
         outputs.extend(output_notes_hash);
@@ -235,7 +308,7 @@ impl TransactionKernel {
     ///
     /// The data on the stack is expected to be arranged as follows:
     ///
-    /// Stack: [CNC, FAH, tx_expiration_block_num]
+    /// Stack: [CNC, FAH, tx_expiration_block_num, 0, 0, 0, LC]
     ///
     /// Where:
     /// - CNC is the commitment to the notes created by the transaction.
@@ -244,28 +317,22 @@ impl TransactionKernel {
     /// - tx_expiration_block_num is the block height at which the transaction will become expired,
     ///   defined by the sum of the execution block ref and the transaction's block expiration delta
     ///   (if set during transaction execution).
+    /// - LC is the commitment to the log entries emitted by the transaction, or an EMPTY_WORD if no
+    ///   log entries were emitted.
     ///
     /// # Errors
     /// Returns an error if:
-    /// - Words 3 and 4 on the stack are not 0.
-    /// - Overflow addresses are not empty.
+    /// - The stack does not contain all of the above values.
+    /// - Any of the elements between the expiration block number and the logs commitment is not
+    ///   zero.
+    /// - The expiration block number does not fit into a u32.
     pub fn parse_output_stack(
         stack: &StackOutputs,
-    ) -> Result<(Digest, Digest, BlockNumber), TransactionOutputError> {
-        let output_notes_hash = stack
-            .get_stack_word(OUTPUT_NOTES_COMMITMENT_WORD_IDX * 4)
-            .expect("first word missing")
-            .into();
-
-        let final_account_hash = stack
-            .get_stack_word(FINAL_ACCOUNT_HASH_WORD_IDX * 4)
-            .expect("second word missing")
-            .into();
-
-        let expiration_block_num = stack
-            .get_stack_item(EXPIRATION_BLOCK_ELEMENT_IDX)
-            .expect("element on index 8 missing");
+    ) -> Result<TxKernelOutputs, TransactionOutputError> {
+        let output_notes_commitment = get_stack_word(stack, OUTPUT_NOTES_COMMITMENT_WORD_IDX)?;
+        let final_account_hash = get_stack_word(stack, FINAL_ACCOUNT_HASH_WORD_IDX)?;
 
+        let expiration_block_num = get_stack_item(stack, EXPIRATION_BLOCK_ELEMENT_IDX)?;
         let expiration_block_num = u32::try_from(expiration_block_num.as_int())
             .map_err(|_| {
                 TransactionOutputError::OutputStackInvalid(
@@ -274,13 +341,23 @@ impl TransactionKernel {
             })?
             .into();
 
-        if stack.get_stack_word(12).expect("fourth word missing") != EMPTY_WORD {
-            return Err(TransactionOutputError::OutputStackInvalid(
-                "Fourth word on output stack should consist only of ZEROs".into(),
-            ));
+        for index in EXPIRATION_BLOCK_ELEMENT_IDX + 1..LOGS_COMMITMENT_WORD_IDX * WORD_SIZE {
+            let value = get_stack_item(stack, index)?;
+            if value != ZERO {
+                return Err(TransactionOutputError::OutputStackOverflowNotZero { index, value });
+            }
         }
 
-        Ok((final_account_hash, output_notes_hash, expiration_block_num))
+        let logs_commitment = get_stack_word(stack, LOGS_COMMITMENT_WORD_IDX)?;
+        let logs_commitment =
+            (logs_commitment != Digest::from(EMPTY_WORD)).then_some(logs_commitment);
+
+        Ok(TxKernelOutputs {
+            final_account_hash,
+            output_notes_commitment,
+            expiration_block_num,
+            logs_commitment,
+        })
     }
 
     // TRANSACTION OUTPUT PARSER
@@ -290,7 +367,7 @@ impl TransactionKernel {
     ///
     /// The output stack is expected to be arrange as follows:
     ///
-    /// Stack: [CNC, FAH, tx_expiration_block_num]
+    /// Stack: [CNC, FAH, tx_expiration_block_num, 0, 0, 0, LC]
     ///
     /// Where:
     /// - CNC is the commitment to the notes created by the transaction.
@@ -299,6 +376,7 @@ impl TransactionKernel {
     /// - tx_expiration_block_num is the block height at which the transaction will become expired,
     ///   defined by the sum of the execution block ref and the transaction's block expiration delta
     ///   (if set during transaction execution).
+    /// - LC is the commitment to the log entries emitted by the transaction.
     ///
     /// The actual data describing the new account state and output notes is expected to be located
     /// in the provided advice map under keys CNC and FAH.
@@ -307,22 +385,26 @@ impl TransactionKernel {
         adv_map: &AdviceMap,
         output_notes: Vec<OutputNote>,
     ) -> Result<TransactionOutputs, TransactionOutputError> {
-        let (final_acct_hash, output_notes_hash, expiration_block_num) =
-            Self::parse_output_stack(stack)?;
+        let TxKernelOutputs {
+            final_account_hash,
+            output_notes_commitment,
+            expiration_block_num,
+            logs_commitment,
+        } = Self::parse_output_stack(stack)?;
 
         // parse final account state
         let final_account_data = adv_map
-            .get(&final_acct_hash)
+            .get(&final_account_hash)
             .ok_or(TransactionOutputError::FinalAccountHashMissingInAdviceMap)?;
         let account = parse_final_account_header(final_account_data)
             .map_err(TransactionOutputError::FinalAccountHeaderParseFailure)?;
 
         // validate output notes
         let output_notes = OutputNotes::new(output_notes)?;
-        if output_notes_hash != output_notes.commitment() {
+        if output_notes_commitment != output_notes.commitment() {
             return Err(TransactionOutputError::OutputNotesCommitmentInconsistent {
                 actual: output_notes.commitment(),
-                expected: output_notes_hash,
+                expected: output_notes_commitment,
             });
         }
 
@@ -330,6 +412,7 @@ impl TransactionKernel {
             account,
             output_notes,
             expiration_block_num,
+            logs_commitment,
         })
     }
 }
@@ -352,15 +435,9 @@ impl TransactionKernel {
     /// the kernel binary (`main.masm`) include this code, it is not exposed explicitly. By adding
     /// it separately, we can expose procedures from `/lib` and test them individually.
     pub fn testing_assembler() -> Assembler {
-        let source_manager = Arc::new(DefaultSourceManager::default());
-        let kernel_library = Self::kernel_as_library();
-
-        Assembler::with_kernel(source_manager, Self::kernel())
-            .with_library(StdLibrary::default())
-            .expect("failed to load std-lib")
-            .with_library(MidenLib::default())
-            .expect("failed to load miden-lib")
-            .with_library(kernel_library)
+        TransactionAssemblerBuilder::new()
+            .with_library(Self::kernel_as_library())
+            .build()
             .expect("failed to load kernel library (/lib)")
     }
 
@@ -373,3 +450,80 @@ impl TransactionKernel {
         assembler.with_library(library).expect("failed to add mock account code")
     }
 }
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the element at the specified index of the output stack.
+fn get_stack_item(stack: &StackOutputs, index: usize) -> Result<Felt, TransactionOutputError> {
+    stack
+        .get_stack_item(index)
+        .ok_or(TransactionOutputError::OutputStackTooShort(index))
+}
+
+/// Returns the word at the specified word index of the output stack.
+fn get_stack_word(
+    stack: &StackOutputs,
+    word_index: usize,
+) -> Result<Digest, TransactionOutputError> {
+    let index = word_index * WORD_SIZE;
+    stack
+        .get_stack_word(index)
+        .map(Digest::from)
+        .ok_or(TransactionOutputError::OutputStackTooShort(index + WORD_SIZE - 1))
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use miden_objects::ONE;
+
+    use super::*;
+
+    #[test]
+    fn parse_output_stack() {
+        let final_account_hash = Digest::from([ONE, ZERO, ZERO, ZERO]);
+        let output_notes_commitment = Digest::from([ZERO, ONE, ZERO, ZERO]);
+        let logs_commitment = Digest::from([ZERO, ZERO, ONE, ZERO]);
+        let expiration_block_num = BlockNumber::from(42);
+
+        let stack = TransactionKernel::build_output_stack(
+            final_account_hash,
+            output_notes_commitment,
+            expiration_block_num,
+            Some(logs_commitment),
+        );
+        assert_eq!(
+            TransactionKernel::parse_output_stack(&stack).unwrap(),
+            TxKernelOutputs {
+                final_account_hash,
+                output_notes_commitment,
+                expiration_block_num,
+                logs_commitment: Some(logs_commitment),
+            }
+        );
+
+        let mut stack = TransactionKernel::build_output_stack(
+            final_account_hash,
+            output_notes_commitment,
+            expiration_block_num,
+            None,
+        );
+        assert_eq!(TransactionKernel::parse_output_stack(&stack).unwrap().logs_commitment, None);
+
+        stack.stack_mut()[EXPIRATION_BLOCK_ELEMENT_IDX + 2] = ONE;
+        assert!(matches!(
+            TransactionKernel::parse_output_stack(&stack),
+            Err(TransactionOutputError::OutputStackOverflowNotZero { index, value })
+                if index == EXPIRATION_BLOCK_ELEMENT_IDX + 2 && value == ONE
+        ));
+
+        stack.stack_mut()[EXPIRATION_BLOCK_ELEMENT_IDX] = Felt::new(u64::from(u32::MAX) + 1);
+        assert!(matches!(
+            TransactionKernel::parse_output_stack(&stack),
+            Err(TransactionOutputError::OutputStackInvalid(_))
+        ));
+    }
+}
@@ -1,6 +1,7 @@
 use miden_objects::{
     account::{AccountHeader, AccountId},
-    AccountError, Felt, Word, WORD_SIZE,
+    block::BlockNumber,
+    AccountError, Digest, Felt, Word, WORD_SIZE,
 };
 
 use super::memory::{
@@ -21,6 +22,27 @@ pub const FINAL_ACCOUNT_HASH_WORD_IDX: usize = 1;
 /// The index of the item at which the expiration block height is stored on the output stack.
 pub const EXPIRATION_BLOCK_ELEMENT_IDX: usize = 8;
 
+/// The index of the word at which the commitment to the emitted log entries is stored on the output
+/// stack.
+pub const LOGS_COMMITMENT_WORD_IDX: usize = 3;
+
+// TRANSACTION KERNEL OUTPUTS
+// ================================================================================================
+
+/// The outputs of the transaction kernel extracted from the output stack of the VM.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TxKernelOutputs {
+    /// The hash of the final state of the account the transaction was executed against.
+    pub final_account_hash: Digest,
+    /// The commitment to the notes created by the transaction.
+    pub output_notes_commitment: Digest,
+    /// The block number at which the transaction expires.
+    pub expiration_block_num: BlockNumber,
+    /// The commitment to the log entries emitted by the transaction, or `None` if no log entries
+    /// were emitted.
+    pub logs_commitment: Option<Digest>,
+}
+
 // ACCOUNT HEADER EXTRACTOR
 // ================================================================================================
 
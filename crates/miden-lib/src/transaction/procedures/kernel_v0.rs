@@ -6,7 +6,7 @@ use miden_objects::{digest, Digest};
 // ================================================================================================
 
 /// Hashes of all dynamically executed procedures from the kernel 0.
-pub const KERNEL0_PROCEDURES: [Digest; 35] = [
+pub const KERNEL0_PROCEDURES: [Digest; 38] = [
     // account_get_initial_hash
     digest!("0x920898348bacd6d98a399301eb308478fd32b32eab019a5a6ef7a6b44abb61f6"),
     // account_get_current_hash
@@ -24,7 +24,7 @@ pub const KERNEL0_PROCEDURES: [Digest; 35] = [
     // account_get_item
     digest!("0x05b08e5241a702b90b8623cd97ba83f7c608941dcc73ad4f2ffc71a7f04eb61e"),
     // account_set_item
-    digest!("0x1d87539d6557cc970fc78bfde945d867858c2e6183bee1dc88aceb9b0acfbe21"),
+    digest!("0x44c90f9c136df6558f6a2158fcb6d79326716394cc7e5431e6aedd89bb649b5d"),
     // account_get_map_item
     digest!("0xc00e1cd25d6a412624d8f69ec74630688868a56e1c7ad94125ef3eb0da80acd6"),
     // account_set_map_item
@@ -77,4 +77,10 @@ pub const KERNEL0_PROCEDURES: [Digest; 35] = [
     digest!("0x4c3ca7bb7dac8ae20aefe6ebe582499730cd5ffa3d2592ac88b83a4d72873089"),
     // tx_update_expiration_block_num
     digest!("0xa7b1045569f0905558f38454bfc4b6bbbd07648e34248161a4bb44cfb557043d"),
+    // tx_emit_log
+    digest!("0x2a3aa1b36821bbda588ff423a8c9e5e0979aceb0d6b0861a521208482e3a9385"),
+    // account_set_code
+    digest!("0xaf2c541c27b0c44293e389d8d5c432b4992985fbdd8efde99e3f05bb7502c572"),
+    // account_get_new_code_commitment
+    digest!("0x212b69ccafbd3a5261d08ef5fbc5c1777a23dee1895053334eb8bea98be3984e"),
 ];
@@ -0,0 +1,377 @@
+//! JSON rendering of [AccountDelta] and [AccountUpdateDetails].
+//!
+//! The produced structure is stable and is intended to be consumed by explorers, indexers and
+//! other tooling. Words and digests are rendered as `0x`-prefixed hex strings of their 32-byte
+//! little-endian encoding, account IDs and ID prefixes via their `to_hex()` representation.
+//!
+//! An [AccountDelta] is rendered as:
+//!
+//! ```json
+//! {
+//!   "nonce": 2,
+//!   "storage": {
+//!     "values": [{ "slot": 0, "value": "0x..." }],
+//!     "maps": [{ "slot": 1, "entries": [{ "key": "0x...", "value": "0x..." }] }]
+//!   },
+//!   "vault": {
+//!     "fungible": [{ "faucet_id": "0x...", "amount": -100 }],
+//!     "non_fungible": [{ "action": "add", "faucet_id_prefix": "0x...", "asset": "0x..." }]
+//!   }
+//! }
+//! ```
+//!
+//! where `nonce` is `null` if the nonce was not updated, `amount` is the signed change of the
+//! balance of the fungible asset issued by `faucet_id` and `action` is either `"add"` or
+//! `"remove"`. Slots, map entries and assets are sorted in ascending order.
+//!
+//! [AccountUpdateDetails] are tagged by their `type`:
+//! - `{ "type": "private" }` for private accounts.
+//! - `{ "type": "delta", "delta": { ... } }` for updates of existing public accounts, where `delta`
+//!   is rendered as described above.
+//! - `{ "type": "code_upgrade", "code_commitment": "0x...", "delta": { ... } }` for updates of
+//!   existing public accounts which also upgraded the account code to the code with the specified
+//!   commitment.
+//! - `{ "type": "new", "account": { ... } }` for new public accounts, where `account` contains the
+//!   `account_id`, `nonce` and `code_commitment` of the account as well as its full `storage` and
+//!   `vault`, rendered in the same way as the corresponding parts of a delta with all fungible
+//!   amounts being positive and all non-fungible assets being added.
+
+use alloc::{string::String, vec::Vec};
+
+use serde::{Serialize, Serializer};
+
+use super::{
+    AccountDelta, AccountStorageDelta, AccountUpdateDetails, AccountVaultDelta,
+    NonFungibleDeltaAction, Word,
+};
+use crate::{account::Account, Digest};
+
+// SERIALIZE IMPLEMENTATIONS
+// ================================================================================================
+
+impl Serialize for AccountDelta {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        AccountDeltaJson::from(self).serialize(serializer)
+    }
+}
+
+impl Serialize for AccountStorageDelta {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        StorageDeltaJson::from(self).serialize(serializer)
+    }
+}
+
+impl Serialize for AccountVaultDelta {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        VaultDeltaJson::from(self).serialize(serializer)
+    }
+}
+
+impl Serialize for AccountUpdateDetails {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let details = match self {
+            AccountUpdateDetails::Private => AccountUpdateDetailsJson::Private,
+            AccountUpdateDetails::New(account) => {
+                AccountUpdateDetailsJson::New { account: NewAccountJson::from(account) }
+            },
+            AccountUpdateDetails::Delta(delta) => {
+                AccountUpdateDetailsJson::Delta { delta: AccountDeltaJson::from(delta) }
+            },
+            AccountUpdateDetails::CodeUpgrade(code, delta) => {
+                AccountUpdateDetailsJson::CodeUpgrade {
+                    code_commitment: code.commitment().to_hex(),
+                    delta: AccountDeltaJson::from(delta),
+                }
+            },
+        };
+
+        details.serialize(serializer)
+    }
+}
+
+// JSON VIEWS
+// ================================================================================================
+
+#[derive(Serialize)]
+struct AccountDeltaJson {
+    nonce: Option<u64>,
+    storage: StorageDeltaJson,
+    vault: VaultDeltaJson,
+}
+
+impl From<&AccountDelta> for AccountDeltaJson {
+    fn from(delta: &AccountDelta) -> Self {
+        Self {
+            nonce: delta.nonce().map(|nonce| nonce.as_int()),
+            storage: delta.storage().into(),
+            vault: delta.vault().into(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct StorageDeltaJson {
+    values: Vec<StorageValueJson>,
+    maps: Vec<StorageMapJson>,
+}
+
+#[derive(Serialize)]
+struct StorageValueJson {
+    slot: u8,
+    value: String,
+}
+
+#[derive(Serialize)]
+struct StorageMapJson {
+    slot: u8,
+    entries: Vec<StorageMapEntryJson>,
+}
+
+#[derive(Serialize)]
+struct StorageMapEntryJson {
+    key: String,
+    value: String,
+}
+
+impl From<&AccountStorageDelta> for StorageDeltaJson {
+    fn from(delta: &AccountStorageDelta) -> Self {
+        let values = delta
+            .values()
+            .iter()
+            .map(|(&slot, value)| StorageValueJson { slot, value: word_to_hex(value) })
+            .collect();
+
+        let maps = delta
+            .maps()
+            .iter()
+            .map(|(&slot, map)| StorageMapJson {
+                slot,
+                entries: map
+                    .leaves()
+                    .iter()
+                    .map(|(key, value)| StorageMapEntryJson {
+                        key: key.to_hex(),
+                        value: word_to_hex(value),
+                    })
+                    .collect(),
+            })
+            .collect();
+
+        Self { values, maps }
+    }
+}
+
+#[derive(Serialize)]
+struct VaultDeltaJson {
+    fungible: Vec<FungibleAssetDeltaJson>,
+    non_fungible: Vec<NonFungibleAssetDeltaJson>,
+}
+
+#[derive(Serialize)]
+struct FungibleAssetDeltaJson {
+    faucet_id: String,
+    amount: i64,
+}
+
+#[derive(Serialize)]
+struct NonFungibleAssetDeltaJson {
+    action: &'static str,
+    faucet_id_prefix: String,
+    asset: String,
+}
+
+impl From<&AccountVaultDelta> for VaultDeltaJson {
+    fn from(delta: &AccountVaultDelta) -> Self {
+        let fungible = delta
+            .fungible()
+            .iter()
+            .map(|(faucet_id, &amount)| FungibleAssetDeltaJson {
+                faucet_id: faucet_id.to_hex(),
+                amount,
+            })
+            .collect();
+
+        let non_fungible = delta
+            .non_fungible()
+            .iter()
+            .map(|(asset, action)| NonFungibleAssetDeltaJson {
+                action: match action {
+                    NonFungibleDeltaAction::Add => "add",
+                    NonFungibleDeltaAction::Remove => "remove",
+                },
+                faucet_id_prefix: asset.faucet_id_prefix().to_hex(),
+                asset: word_to_hex(&Word::from(*asset)),
+            })
+            .collect();
+
+        Self { fungible, non_fungible }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum AccountUpdateDetailsJson {
+    Private,
+    New {
+        account: NewAccountJson,
+    },
+    Delta {
+        delta: AccountDeltaJson,
+    },
+    CodeUpgrade {
+        code_commitment: String,
+        delta: AccountDeltaJson,
+    },
+}
+
+#[derive(Serialize)]
+struct NewAccountJson {
+    account_id: String,
+    nonce: u64,
+    code_commitment: String,
+    storage: StorageDeltaJson,
+    vault: VaultDeltaJson,
+}
+
+impl From<&Account> for NewAccountJson {
+    fn from(account: &Account) -> Self {
+        Self {
+            account_id: account.id().to_hex(),
+            nonce: account.nonce().as_int(),
+            code_commitment: account.code().commitment().to_hex(),
+            storage: (&AccountStorageDelta::from(account.storage().clone())).into(),
+            vault: (&AccountVaultDelta::from(account.vault())).into(),
+        }
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the hex representation of the provided word.
+fn word_to_hex(word: &Word) -> String {
+    Digest::from(*word).to_hex()
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        account::{AccountCode, AccountId, AccountStorage, StorageMapDelta},
+        asset::{AssetVault, FungibleAsset, NonFungibleAsset, NonFungibleAssetDetails},
+        testing::account_id::{
+            ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN,
+            ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN,
+        },
+        Felt, ONE, ZERO,
+    };
+
+    #[test]
+    fn account_delta_json_structure() {
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let nft_faucet_prefix =
+            AccountId::try_from(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN).unwrap().prefix();
+        let nft = NonFungibleAsset::new(
+            &NonFungibleAssetDetails::new(nft_faucet_prefix, vec![1, 2, 3]).unwrap(),
+        )
+        .unwrap();
+
+        let map_key = Digest::from([ONE, ZERO, ZERO, ZERO]);
+        let storage = AccountStorageDelta::new(
+            [(0, [ONE, ONE, ONE, ONE])].into(),
+            [(1, StorageMapDelta::new([(map_key, [ZERO, ONE, ZERO, ONE])].into()))].into(),
+        )
+        .unwrap();
+        let mut vault = AccountVaultDelta::default();
+        vault.remove_asset(FungibleAsset::new(faucet_id, 100).unwrap().into()).unwrap();
+        vault.add_asset(nft.into()).unwrap();
+
+        let delta = AccountDelta::new(storage, vault, Some(Felt::new(5))).unwrap();
+
+        let expected = json!({
+            "nonce": 5,
+            "storage": {
+                "values": [{ "slot": 0, "value": word_to_hex(&[ONE, ONE, ONE, ONE]) }],
+                "maps": [{
+                    "slot": 1,
+                    "entries": [{
+                        "key": map_key.to_hex(),
+                        "value": word_to_hex(&[ZERO, ONE, ZERO, ONE]),
+                    }],
+                }],
+            },
+            "vault": {
+                "fungible": [{ "faucet_id": faucet_id.to_hex(), "amount": -100 }],
+                "non_fungible": [{
+                    "action": "add",
+                    "faucet_id_prefix": nft_faucet_prefix.to_hex(),
+                    "asset": word_to_hex(&nft.into()),
+                }],
+            },
+        });
+
+        assert_eq!(serde_json::to_value(&delta).unwrap(), expected);
+        assert_eq!(
+            serde_json::to_value(AccountUpdateDetails::Delta(delta)).unwrap(),
+            json!({ "type": "delta", "delta": expected })
+        );
+    }
+
+    #[test]
+    fn account_update_details_json_structure() {
+        assert_eq!(
+            serde_json::to_value(AccountUpdateDetails::Private).unwrap(),
+            json!({ "type": "private" })
+        );
+
+        let code = AccountCode::mock();
+        assert_eq!(
+            serde_json::to_value(AccountUpdateDetails::CodeUpgrade(
+                code.clone(),
+                AccountDelta::default()
+            ))
+            .unwrap(),
+            json!({
+                "type": "code_upgrade",
+                "code_commitment": code.commitment().to_hex(),
+                "delta": serde_json::to_value(AccountDelta::default()).unwrap(),
+            })
+        );
+
+        assert_eq!(
+            serde_json::to_value(AccountDelta::default()).unwrap(),
+            json!({
+                "nonce": null,
+                "storage": { "values": [], "maps": [] },
+                "vault": { "fungible": [], "non_fungible": [] },
+            })
+        );
+
+        let account = Account::from_parts(
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap(),
+            AssetVault::new(&[FungibleAsset::mock(100)]).unwrap(),
+            AccountStorage::mock(),
+            AccountCode::mock(),
+            ONE,
+        );
+        let value = serde_json::to_value(AccountUpdateDetails::New(account.clone())).unwrap();
+
+        assert_eq!(value["type"], "new");
+        assert_eq!(value["account"]["account_id"], account.id().to_hex());
+        assert_eq!(value["account"]["nonce"], 1);
+        assert_eq!(value["account"]["code_commitment"], account.code().commitment().to_hex());
+        assert_eq!(
+            value["account"]["storage"],
+            serde_json::to_value(AccountStorageDelta::from(account.storage().clone())).unwrap()
+        );
+        assert_eq!(
+            value["account"]["vault"]["fungible"],
+            json!([{ "faucet_id": FungibleAsset::mock_issuer().to_hex(), "amount": 100 }])
+        );
+    }
+}
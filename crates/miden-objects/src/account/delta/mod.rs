@@ -1,10 +1,14 @@
 use alloc::string::ToString;
+use core::fmt;
 
 use super::{
-    Account, ByteReader, ByteWriter, Deserializable, DeserializationError, Felt, Serializable,
-    Word, ZERO,
+    Account, AccountCode, ByteReader, ByteWriter, Deserializable, DeserializationError, Felt,
+    Serializable, Word, ZERO,
 };
-use crate::AccountDeltaError;
+use crate::{AccountDeltaError, Digest};
+
+#[cfg(feature = "std")]
+mod json;
 
 mod storage;
 pub use storage::{AccountStorageDelta, StorageMapDelta};
@@ -108,6 +112,13 @@ pub enum AccountUpdateDetails {
 
     /// For existing accounts, only the delta is needed.
     Delta(AccountDelta),
+
+    /// For existing accounts whose code was upgraded, the new code is needed in addition to the
+    /// delta.
+    ///
+    /// The update is applied by applying the delta to the account and replacing the code of the
+    /// account with the new code via [Account::upgrade_code].
+    CodeUpgrade(AccountCode, AccountDelta),
 }
 
 impl AccountUpdateDetails {
@@ -134,10 +145,41 @@ impl AccountUpdateDetails {
 
                 AccountUpdateDetails::New(account)
             },
+            (
+                AccountUpdateDetails::New(mut account),
+                AccountUpdateDetails::CodeUpgrade(code, delta),
+            ) => {
+                account.apply_delta(&delta).and_then(|_| account.upgrade_code(code)).map_err(
+                    |err| AccountDeltaError::AccountDeltaApplicationFailed {
+                        account_id: account.id(),
+                        source: err,
+                    },
+                )?;
+
+                AccountUpdateDetails::New(account)
+            },
             (AccountUpdateDetails::Delta(mut delta), AccountUpdateDetails::Delta(new_delta)) => {
                 delta.merge(new_delta)?;
                 AccountUpdateDetails::Delta(delta)
             },
+            (
+                AccountUpdateDetails::Delta(mut delta),
+                AccountUpdateDetails::CodeUpgrade(code, new_delta),
+            )
+            | (
+                AccountUpdateDetails::CodeUpgrade(_, mut delta),
+                AccountUpdateDetails::CodeUpgrade(code, new_delta),
+            ) => {
+                delta.merge(new_delta)?;
+                AccountUpdateDetails::CodeUpgrade(code, delta)
+            },
+            (
+                AccountUpdateDetails::CodeUpgrade(code, mut delta),
+                AccountUpdateDetails::Delta(new_delta),
+            ) => {
+                delta.merge(new_delta)?;
+                AccountUpdateDetails::CodeUpgrade(code, delta)
+            },
             (left, right) => {
                 return Err(AccountDeltaError::IncompatibleAccountUpdates {
                     left_update_type: left.as_tag_str(),
@@ -155,6 +197,7 @@ impl AccountUpdateDetails {
             AccountUpdateDetails::Private => "private",
             AccountUpdateDetails::New(_) => "new",
             AccountUpdateDetails::Delta(_) => "delta",
+            AccountUpdateDetails::CodeUpgrade(..) => "code upgrade",
         }
     }
 }
@@ -213,6 +256,11 @@ impl Serializable for AccountUpdateDetails {
                 2_u8.write_into(target);
                 delta.write_into(target);
             },
+            AccountUpdateDetails::CodeUpgrade(code, delta) => {
+                3_u8.write_into(target);
+                code.write_into(target);
+                delta.write_into(target);
+            },
         }
     }
 
@@ -224,6 +272,9 @@ impl Serializable for AccountUpdateDetails {
             AccountUpdateDetails::Private => u8_size,
             AccountUpdateDetails::New(account) => u8_size + account.get_size_hint(),
             AccountUpdateDetails::Delta(account_delta) => u8_size + account_delta.get_size_hint(),
+            AccountUpdateDetails::CodeUpgrade(code, account_delta) => {
+                u8_size + code.get_size_hint() + account_delta.get_size_hint()
+            },
         }
     }
 }
@@ -234,6 +285,10 @@ impl Deserializable for AccountUpdateDetails {
             0 => Ok(Self::Private),
             1 => Ok(Self::New(Account::read_from(source)?)),
             2 => Ok(Self::Delta(AccountDelta::read_from(source)?)),
+            3 => Ok(Self::CodeUpgrade(
+                AccountCode::read_from(source)?,
+                AccountDelta::read_from(source)?,
+            )),
             v => Err(DeserializationError::InvalidValue(format!(
                 "Unknown variant {v} for AccountDetails"
             ))),
@@ -241,6 +296,98 @@ impl Deserializable for AccountUpdateDetails {
     }
 }
 
+// DISPLAY
+// ================================================================================================
+
+/// Renders the delta as a human-readable multi-line summary of the changed storage slots and map
+/// entries, the signed balance changes of fungible assets and the added and removed non-fungible
+/// assets.
+impl fmt::Display for AccountDelta {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.nonce.is_none() && self.is_empty() {
+            return f.write_str("no changes");
+        }
+
+        match self.nonce {
+            Some(nonce) => write!(f, "nonce: {nonce}")?,
+            None => f.write_str("nonce: unchanged")?,
+        }
+        fmt_storage_delta(f, &self.storage)?;
+        fmt_vault_delta(f, &self.vault)
+    }
+}
+
+/// Renders the update details as a human-readable multi-line summary.
+///
+/// For new accounts the full initial state of the account is rendered in the same way as the
+/// changes of an [AccountDelta]. For code upgrades the commitment to the new code precedes the
+/// rendered delta.
+impl fmt::Display for AccountUpdateDetails {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AccountUpdateDetails::Private => f.write_str("private account"),
+            AccountUpdateDetails::New(account) => {
+                write!(f, "new account {}", account.id())?;
+                write!(f, "\nnonce: {}", account.nonce())?;
+                write!(f, "\ncode commitment: {}", account.code().commitment().to_hex())?;
+                fmt_storage_delta(f, &account.storage().clone().into())?;
+                fmt_vault_delta(f, &account.vault().into())
+            },
+            AccountUpdateDetails::Delta(delta) => write!(f, "{delta}"),
+            AccountUpdateDetails::CodeUpgrade(code, delta) => {
+                write!(f, "code upgrade to {}", code.commitment().to_hex())?;
+                write!(f, "\n{delta}")
+            },
+        }
+    }
+}
+
+/// Writes the lines describing the provided storage delta.
+fn fmt_storage_delta(f: &mut fmt::Formatter<'_>, storage: &AccountStorageDelta) -> fmt::Result {
+    if storage.is_empty() {
+        return f.write_str("\nstorage: no changes");
+    }
+
+    f.write_str("\nstorage:")?;
+    for (slot, value) in storage.values() {
+        write!(f, "\n  slot {slot}: {}", Digest::from(*value).to_hex())?;
+    }
+    for (slot, map) in storage.maps() {
+        write!(f, "\n  slot {slot} (map):")?;
+        for (key, value) in map.leaves() {
+            write!(f, "\n    {} -> {}", key.to_hex(), Digest::from(*value).to_hex())?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Writes the lines describing the provided vault delta.
+fn fmt_vault_delta(f: &mut fmt::Formatter<'_>, vault: &AccountVaultDelta) -> fmt::Result {
+    if vault.is_empty() {
+        return f.write_str("\nvault: no changes");
+    }
+
+    f.write_str("\nvault:")?;
+    for (faucet_id, amount) in vault.fungible().iter() {
+        write!(f, "\n  {faucet_id}: {amount:+}")?;
+    }
+    for (asset, action) in vault.non_fungible().iter() {
+        let action = match action {
+            NonFungibleDeltaAction::Add => "added",
+            NonFungibleDeltaAction::Remove => "removed",
+        };
+        write!(
+            f,
+            "\n  {}: {action} {}",
+            asset.faucet_id_prefix(),
+            Digest::from(Word::from(*asset)).to_hex()
+        )?;
+    }
+
+    Ok(())
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
@@ -279,6 +426,7 @@ fn validate_nonce(
 
 #[cfg(test)]
 mod tests {
+    use alloc::string::ToString;
 
     use vm_core::{utils::Serializable, Felt, FieldElement};
 
@@ -292,7 +440,7 @@ mod tests {
         testing::account_id::{
             AccountIdBuilder, ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN,
         },
-        ONE, ZERO,
+        Digest, ONE, ZERO,
     };
 
     #[test]
@@ -389,10 +537,94 @@ mod tests {
         let update_details_private = AccountUpdateDetails::Private;
         assert_eq!(update_details_private.to_bytes().len(), update_details_private.get_size_hint());
 
-        let update_details_delta = AccountUpdateDetails::Delta(account_delta);
+        let update_details_delta = AccountUpdateDetails::Delta(account_delta.clone());
         assert_eq!(update_details_delta.to_bytes().len(), update_details_delta.get_size_hint());
 
+        let update_details_upgrade =
+            AccountUpdateDetails::CodeUpgrade(account.code().clone(), account_delta);
+        assert_eq!(update_details_upgrade.to_bytes().len(), update_details_upgrade.get_size_hint());
+
         let update_details_new = AccountUpdateDetails::New(account);
         assert_eq!(update_details_new.to_bytes().len(), update_details_new.get_size_hint());
     }
+
+    #[test]
+    fn account_update_details_code_upgrade_merge() {
+        let account_id =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
+        let new_code = AccountCode::mock();
+
+        let delta = |nonce: u64| {
+            AccountDelta::new(
+                AccountStorageDelta::default(),
+                AccountVaultDelta::default(),
+                Some(Felt::new(nonce)),
+            )
+            .unwrap()
+        };
+
+        // a code upgrade followed by a delta remains a code upgrade
+        let merged = AccountUpdateDetails::CodeUpgrade(new_code.clone(), delta(2))
+            .merge(AccountUpdateDetails::Delta(delta(3)))
+            .unwrap();
+        assert_eq!(merged, AccountUpdateDetails::CodeUpgrade(new_code.clone(), delta(3)));
+
+        // a delta followed by a code upgrade becomes a code upgrade
+        let merged = AccountUpdateDetails::Delta(delta(2))
+            .merge(AccountUpdateDetails::CodeUpgrade(new_code.clone(), delta(3)))
+            .unwrap();
+        assert_eq!(merged, AccountUpdateDetails::CodeUpgrade(new_code.clone(), delta(3)));
+
+        // the code of a new account is replaced by the upgraded code
+        let account = Account::from_parts(
+            account_id,
+            AssetVault::default(),
+            AccountStorage::mock(),
+            AccountCode::mock(),
+            Felt::ZERO,
+        );
+        let merged = AccountUpdateDetails::New(account)
+            .merge(AccountUpdateDetails::CodeUpgrade(new_code.clone(), delta(1)))
+            .unwrap();
+        let AccountUpdateDetails::New(account) = merged else {
+            panic!("expected new account update details");
+        };
+        assert_eq!(account.code(), &new_code);
+        assert_eq!(account.nonce(), Felt::new(1));
+
+        // private updates cannot be merged with code upgrades
+        assert!(AccountUpdateDetails::Private
+            .merge(AccountUpdateDetails::CodeUpgrade(new_code, delta(1)))
+            .is_err());
+    }
+
+    #[test]
+    fn account_delta_display() {
+        assert_eq!(AccountDelta::default().to_string(), "no changes");
+        assert_eq!(AccountUpdateDetails::Private.to_string(), "private account");
+
+        let faucet_id = FungibleAsset::mock_issuer();
+        let mut vault_delta = AccountVaultDelta::default();
+        vault_delta
+            .remove_asset(FungibleAsset::new(faucet_id, 25).unwrap().into())
+            .unwrap();
+
+        let map_key = [ONE, ZERO, ZERO, ZERO];
+        let storage_delta = AccountStorageDelta::from_iters(
+            [],
+            [(0, [ONE, ONE, ONE, ONE])],
+            [(1, StorageMapDelta::from_iters([], [(map_key, [ZERO, ZERO, ZERO, ONE])]))],
+        );
+
+        let delta = AccountDelta::new(storage_delta, vault_delta, Some(Felt::new(3))).unwrap();
+        let expected = format!(
+            "nonce: 3\nstorage:\n  slot 0: {}\n  slot 1 (map):\n    {} -> {}\nvault:\n  {faucet_id}: -25",
+            Digest::from([ONE, ONE, ONE, ONE]).to_hex(),
+            Digest::from(map_key).to_hex(),
+            Digest::from([ZERO, ZERO, ZERO, ONE]).to_hex(),
+        );
+
+        assert_eq!(delta.to_string(), expected);
+        assert_eq!(AccountUpdateDetails::Delta(delta).to_string(), expected);
+    }
 }
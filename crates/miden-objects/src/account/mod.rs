@@ -34,7 +34,10 @@ pub use delta::{
 };
 
 mod storage;
-pub use storage::{AccountStorage, AccountStorageHeader, StorageMap, StorageSlot, StorageSlotType};
+pub use storage::{
+    AccountStorage, AccountStorageHeader, StorageMap, StorageMapWitness, StorageSlot,
+    StorageSlotType,
+};
 
 mod header;
 pub use header::AccountHeader;
@@ -285,6 +288,26 @@ impl Account {
         Ok(())
     }
 
+    /// Replaces the code of this account with the provided code.
+    ///
+    /// Only the code of regular accounts with updatable code can be upgraded. The storage of the
+    /// account is left unchanged, so the new code must be compatible with its storage layout.
+    ///
+    /// Note that upgrading the code does not increment the nonce of this account; the nonce is
+    /// expected to be updated by the delta of the transaction which upgraded the code.
+    ///
+    /// # Errors
+    /// Returns an error if this account is not a regular account with updatable code.
+    pub fn upgrade_code(&mut self, code: AccountCode) -> Result<(), AccountError> {
+        if self.account_type() != AccountType::RegularAccountUpdatableCode {
+            return Err(AccountError::AccountCodeNotUpdatable(self.id));
+        }
+
+        self.code = code;
+
+        Ok(())
+    }
+
     // TEST HELPERS
     // --------------------------------------------------------------------------------------------
 
@@ -1,4 +1,6 @@
-use miden_crypto::merkle::EmptySubtreeRoots;
+use alloc::{string::ToString, vec::Vec};
+
+use miden_crypto::{merkle::EmptySubtreeRoots, Felt};
 
 use super::{
     ByteReader, ByteWriter, Deserializable, DeserializationError, Digest, Serializable, Word,
@@ -7,7 +9,7 @@ use crate::{
     account::StorageMapDelta,
     crypto::{
         hash::rpo::RpoDigest,
-        merkle::{InnerNodeInfo, LeafIndex, Smt, SmtLeaf, SmtProof, SMT_DEPTH},
+        merkle::{InnerNodeInfo, LeafIndex, MerklePath, Smt, SmtLeaf, SmtProof, SMT_DEPTH},
     },
 };
 
@@ -75,8 +77,12 @@ impl StorageMap {
         self.map.get_value(key) // Delegate to Smt's get_value method
     }
 
-    pub fn open(&self, key: &RpoDigest) -> SmtProof {
-        self.map.open(key) // Delegate to Smt's open method
+    /// Returns a [StorageMapWitness] for the provided key.
+    ///
+    /// The witness proves the value associated with the key (which is [Self::EMPTY_VALUE] if the
+    /// key is not in the map) against the root of this map.
+    pub fn open(&self, key: &RpoDigest) -> StorageMapWitness {
+        StorageMapWitness { key: *key, proof: self.map.open(key) }
     }
 
     // ITERATORS
@@ -116,6 +122,79 @@ impl Default for StorageMap {
     }
 }
 
+// STORAGE MAP WITNESS
+// ================================================================================================
+
+/// A witness of the value associated with a single key in a [StorageMap].
+///
+/// The witness consists of the key and the opening of the key's leaf in the map's Sparse Merkle
+/// Tree. It contains exactly the data required by the transaction kernel to read the value of the
+/// key from a map with the witness' root:
+/// - the inner nodes of the leaf's authentication path, which are added to the Merkle store.
+/// - the leaf hash |-> leaf elements entry, which is added to the advice map. This entry is not
+///   required if the leaf is empty.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StorageMapWitness {
+    key: Digest,
+    proof: SmtProof,
+}
+
+impl StorageMapWitness {
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the key of this witness.
+    pub fn key(&self) -> Digest {
+        self.key
+    }
+
+    /// Returns the value associated with the key of this witness.
+    pub fn value(&self) -> Word {
+        self.proof
+            .get(&self.key)
+            .expect("the key of a storage map witness should map to the leaf of its proof")
+    }
+
+    /// Returns the root of the storage map against which this witness was created.
+    pub fn root(&self) -> Digest {
+        self.proof.compute_root()
+    }
+
+    /// Returns the leaf of the storage map which contains the key of this witness.
+    pub fn leaf(&self) -> &SmtLeaf {
+        self.proof.leaf()
+    }
+
+    /// Returns the authentication path of the leaf which contains the key of this witness.
+    pub fn path(&self) -> &MerklePath {
+        self.proof.path()
+    }
+
+    /// Returns an iterator over the inner nodes of the leaf's authentication path.
+    pub fn inner_nodes(&self) -> impl Iterator<Item = InnerNodeInfo> + '_ {
+        let leaf = self.proof.leaf();
+        self.proof
+            .path()
+            .inner_nodes(leaf.index().value(), leaf.hash())
+            .expect("the path of a storage map witness should have the depth of the map")
+    }
+
+    /// Returns the leaf hash |-> leaf elements entry of the leaf which contains the key of this
+    /// witness, or `None` if the leaf is empty.
+    pub fn leaf_entry(&self) -> Option<(Digest, Vec<Felt>)> {
+        let leaf = self.proof.leaf();
+        (!leaf.is_empty()).then(|| (leaf.hash(), leaf.to_elements()))
+    }
+
+    // CONVERSIONS
+    // --------------------------------------------------------------------------------------------
+
+    /// Consumes this witness and returns its underlying [SmtProof].
+    pub fn into_proof(self) -> SmtProof {
+        self.proof
+    }
+}
+
 // SERIALIZATION
 // ================================================================================================
 
@@ -136,11 +215,42 @@ impl Deserializable for StorageMap {
     }
 }
 
+impl Serializable for StorageMapWitness {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.key.write_into(target);
+        self.proof.write_into(target);
+    }
+}
+
+impl Deserializable for StorageMapWitness {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let key = Digest::read_from(source)?;
+        let proof = SmtProof::read_from(source)?;
+
+        if proof.get(&key).is_none() {
+            return Err(DeserializationError::InvalidValue(
+                "storage map witness key does not map to the leaf of its proof".to_string(),
+            ));
+        }
+
+        Ok(Self { key, proof })
+    }
+}
+
+// TESTS
+// ================================================================================================
+
 #[cfg(test)]
 mod tests {
-    use miden_crypto::{hash::rpo::RpoDigest, Felt};
+    use miden_crypto::{
+        hash::rpo::RpoDigest,
+        merkle::{MerkleStore, NodeIndex},
+        Felt,
+    };
 
-    use super::{Deserializable, Serializable, StorageMap, Word, EMPTY_STORAGE_MAP_ROOT};
+    use super::{
+        Deserializable, Serializable, StorageMap, StorageMapWitness, Word, EMPTY_STORAGE_MAP_ROOT,
+    };
 
     #[test]
     fn account_storage_serialization() {
@@ -171,4 +281,34 @@ mod tests {
         // If these values don't match, update the constants.
         assert_eq!(StorageMap::default().root(), EMPTY_STORAGE_MAP_ROOT);
     }
+
+    #[test]
+    fn storage_map_witness() {
+        let key_1 =
+            RpoDigest::new([Felt::new(101), Felt::new(102), Felt::new(103), Felt::new(104)]);
+        let key_2 =
+            RpoDigest::new([Felt::new(105), Felt::new(106), Felt::new(107), Felt::new(108)]);
+        let value_1 = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+        let storage_map = StorageMap::with_entries([(key_1, value_1), (key_2, [Felt::new(5); 4])]);
+
+        let witness = storage_map.open(&key_1);
+        assert_eq!(witness.key(), key_1);
+        assert_eq!(witness.value(), value_1);
+        assert_eq!(witness.root(), storage_map.root());
+        assert_eq!(witness, StorageMapWitness::read_from_bytes(&witness.to_bytes()).unwrap());
+
+        // the inner nodes of the witness are sufficient to authenticate the leaf
+        let leaf = witness.leaf();
+        let store: MerkleStore = witness.inner_nodes().collect();
+        let leaf_index = NodeIndex::new(StorageMap::STORAGE_MAP_TREE_DEPTH, leaf.index().value());
+        assert_eq!(store.get_node(storage_map.root(), leaf_index.unwrap()).unwrap(), leaf.hash());
+        assert_eq!(witness.leaf_entry(), Some((leaf.hash(), leaf.to_elements())));
+
+        // a witness for a key which is not in the map proves the empty value
+        let missing_key = RpoDigest::new([Felt::new(1); 4]);
+        let witness = storage_map.open(&missing_key);
+        assert_eq!(witness.value(), StorageMap::EMPTY_VALUE);
+        assert_eq!(witness.root(), storage_map.root());
+        assert_eq!(witness.leaf_entry(), None);
+    }
 }
@@ -10,7 +10,7 @@ mod slot;
 pub use slot::{StorageSlot, StorageSlotType};
 
 mod map;
-pub use map::StorageMap;
+pub use map::{StorageMap, StorageMapWitness};
 
 mod header;
 pub use header::{AccountStorageHeader, StorageSlotHeader};
@@ -137,6 +137,36 @@ impl AccountStorage {
         }
     }
 
+    /// Returns the [StorageMapWitness]es for the provided keys of the maps located in storage at
+    /// the specified indices.
+    ///
+    /// The witnesses contain exactly the data required by the transaction kernel to read the
+    /// values of the provided keys, e.g. when the account is accessed via foreign procedure
+    /// invocation, without providing the entire storage maps.
+    ///
+    /// # Errors:
+    /// - If any of the indices is out of bounds
+    /// - If any of the [StorageSlot]s is not [StorageSlotType::Map]
+    pub fn open_map_items(
+        &self,
+        items: impl IntoIterator<Item = (u8, Digest)>,
+    ) -> Result<Vec<StorageMapWitness>, AccountError> {
+        items
+            .into_iter()
+            .map(|(index, key)| {
+                match self.slots.get(index as usize).ok_or(
+                    AccountError::StorageIndexOutOfBounds {
+                        slots_len: self.slots.len() as u8,
+                        index,
+                    },
+                )? {
+                    StorageSlot::Map(ref map) => Ok(map.open(&key)),
+                    _ => Err(AccountError::StorageSlotNotMap(index)),
+                }
+            })
+            .collect()
+    }
+
     /// Returns an [AccountStorageHeader] for this account storage.
     pub fn get_header(&self) -> AccountStorageHeader {
         AccountStorageHeader::new(
@@ -0,0 +1,253 @@
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    vec::Vec,
+};
+
+use crate::{
+    account::AccountId,
+    errors::TransactionDependencyError,
+    note::NoteId,
+    transaction::{ProvenTransaction, TransactionId},
+    Digest,
+};
+
+// TRANSACTION DEPENDENCY GRAPH
+// ================================================================================================
+
+/// The dependency graph of a set of proven transactions.
+///
+/// A transaction depends on another transaction of the set if it must be executed after it:
+/// - Account state: a transaction executed against an account state which is the final state of
+///   another transaction depends on that transaction. A transaction which does not change the state
+///   of its account depends on the transaction creating that state, and the transaction changing
+///   that state depends on it.
+/// - Notes: a transaction consuming an unauthenticated note depends on the transaction which
+///   created the note.
+///
+/// The graph can be used by batch and block producers to check that a set of transactions can be
+/// included together and to order the transactions accordingly (see
+/// [TransactionDependencyGraph::into_ordered]).
+#[derive(Debug, Clone)]
+pub struct TransactionDependencyGraph {
+    /// The transactions of the graph in the order in which they were provided.
+    transactions: Vec<ProvenTransaction>,
+
+    /// The indices of the transactions each transaction depends on.
+    dependencies: Vec<BTreeSet<usize>>,
+}
+
+impl TransactionDependencyGraph {
+    /// Returns a new [TransactionDependencyGraph] built from the provided transactions.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The same transaction is provided more than once.
+    /// - The same note is consumed more than once or created more than once.
+    /// - Two transactions against the same account change the account from the same initial state.
+    /// - The state transitions of an account do not form a single chain, i.e. more than one initial
+    ///   state of the account is not the final state of another transaction.
+    pub fn new(transactions: Vec<ProvenTransaction>) -> Result<Self, TransactionDependencyError> {
+        let mut tx_ids = BTreeSet::new();
+        let mut nullifiers = BTreeSet::new();
+        let mut note_creators = BTreeMap::new();
+        for (index, tx) in transactions.iter().enumerate() {
+            if !tx_ids.insert(tx.id()) {
+                return Err(TransactionDependencyError::DuplicateTransaction(tx.id()));
+            }
+
+            for nullifier in tx.get_nullifiers() {
+                if !nullifiers.insert(nullifier) {
+                    return Err(TransactionDependencyError::DuplicateInputNote(nullifier));
+                }
+            }
+
+            for note in tx.output_notes().iter() {
+                if note_creators.insert(note.id(), (index, note.hash())).is_some() {
+                    return Err(TransactionDependencyError::DuplicateOutputNote(note.id()));
+                }
+            }
+        }
+
+        let mut dependencies = vec![BTreeSet::new(); transactions.len()];
+        add_note_dependencies(&transactions, &note_creators, &mut dependencies);
+        add_account_dependencies(&transactions, &mut dependencies)?;
+
+        Ok(Self { transactions, dependencies })
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the transactions of this graph in the order in which they were provided.
+    pub fn transactions(&self) -> &[ProvenTransaction] {
+        &self.transactions
+    }
+
+    /// Returns the IDs of the transactions the transaction with the provided ID depends on, or
+    /// `None` if the transaction is not part of this graph.
+    pub fn dependencies(&self, tx_id: TransactionId) -> Option<Vec<TransactionId>> {
+        let index = self.transactions.iter().position(|tx| tx.id() == tx_id)?;
+
+        Some(
+            self.dependencies[index]
+                .iter()
+                .map(|&dep| self.transactions[dep].id())
+                .collect(),
+        )
+    }
+
+    // CONVERSIONS
+    // --------------------------------------------------------------------------------------------
+
+    /// Consumes this graph and returns its transactions ordered such that every transaction comes
+    /// after all the transactions it depends on.
+    ///
+    /// Transactions which do not depend on each other keep the order in which they were provided.
+    ///
+    /// # Errors
+    /// Returns an error if the dependencies of the transactions form a cycle.
+    pub fn into_ordered(self) -> Result<Vec<ProvenTransaction>, TransactionDependencyError> {
+        let mut dependents = vec![Vec::new(); self.transactions.len()];
+        let mut num_pending: Vec<usize> = self.dependencies.iter().map(BTreeSet::len).collect();
+        for (index, dependencies) in self.dependencies.iter().enumerate() {
+            for &dependency in dependencies {
+                dependents[dependency].push(index);
+            }
+        }
+
+        // always pick the first transaction without pending dependencies to keep the order of
+        // independent transactions
+        let mut ready: BTreeSet<usize> =
+            (0..self.transactions.len()).filter(|&index| num_pending[index] == 0).collect();
+        let mut order = Vec::with_capacity(self.transactions.len());
+        while let Some(index) = ready.pop_first() {
+            order.push(index);
+            for &dependent in &dependents[index] {
+                num_pending[dependent] -= 1;
+                if num_pending[dependent] == 0 {
+                    ready.insert(dependent);
+                }
+            }
+        }
+
+        if order.len() != self.transactions.len() {
+            let transactions = num_pending
+                .iter()
+                .enumerate()
+                .filter(|(_, &pending)| pending > 0)
+                .map(|(index, _)| self.transactions[index].id())
+                .collect();
+            return Err(TransactionDependencyError::DependencyCycle(transactions));
+        }
+
+        let mut transactions: Vec<Option<ProvenTransaction>> =
+            self.transactions.into_iter().map(Some).collect();
+
+        Ok(order
+            .into_iter()
+            .map(|index| transactions[index].take().expect("every index is ordered once"))
+            .collect())
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Adds a dependency from every transaction consuming an unauthenticated note to the transaction
+/// creating that note.
+///
+/// Notes which are created by another transaction with a different note hash are not considered
+/// to be created by that transaction.
+fn add_note_dependencies(
+    transactions: &[ProvenTransaction],
+    note_creators: &BTreeMap<NoteId, (usize, Digest)>,
+    dependencies: &mut [BTreeSet<usize>],
+) {
+    for (index, tx) in transactions.iter().enumerate() {
+        for header in tx.get_unauthenticated_notes() {
+            if let Some(&(creator, note_hash)) = note_creators.get(&header.id()) {
+                if note_hash == header.hash() && creator != index {
+                    dependencies[index].insert(creator);
+                }
+            }
+        }
+    }
+}
+
+/// Adds the dependencies between the transactions executed against the same account.
+///
+/// Transactions changing the account state must form a single chain of state transitions, while
+/// transactions which do not change the account state are placed between the transaction
+/// creating that state and the transaction changing it.
+fn add_account_dependencies(
+    transactions: &[ProvenTransaction],
+    dependencies: &mut [BTreeSet<usize>],
+) -> Result<(), TransactionDependencyError> {
+    let mut accounts: BTreeMap<AccountId, Vec<usize>> = BTreeMap::new();
+    for (index, tx) in transactions.iter().enumerate() {
+        accounts.entry(tx.account_id()).or_default().push(index);
+    }
+
+    for (account_id, indices) in accounts {
+        // the transactions changing the account state, keyed by their initial state, and the
+        // transactions which do not change the account state, keyed by that state
+        let mut transitions: BTreeMap<Digest, usize> = BTreeMap::new();
+        let mut non_transitions: BTreeMap<Digest, Vec<usize>> = BTreeMap::new();
+        let mut final_states: BTreeMap<Digest, usize> = BTreeMap::new();
+        for &index in &indices {
+            let update = transactions[index].account_update();
+            let initial_state = update.init_state_hash();
+            let final_state = update.final_state_hash();
+
+            if initial_state == final_state {
+                non_transitions.entry(initial_state).or_default().push(index);
+                continue;
+            }
+
+            if let Some(other) = transitions.insert(initial_state, index) {
+                return Err(TransactionDependencyError::ConflictingAccountStateTransitions {
+                    account_id,
+                    initial_state,
+                    first: transactions[other].id(),
+                    second: transactions[index].id(),
+                });
+            }
+            final_states.insert(final_state, index);
+        }
+
+        // the transactions executed against a state depend on the transaction creating the state
+        for (&initial_state, &index) in &transitions {
+            if let Some(&creator) = final_states.get(&initial_state) {
+                dependencies[index].insert(creator);
+            }
+        }
+        for (state, indices) in &non_transitions {
+            for &index in indices {
+                if let Some(&creator) = final_states.get(state) {
+                    dependencies[index].insert(creator);
+                }
+                if let Some(&successor) = transitions.get(state) {
+                    dependencies[successor].insert(index);
+                }
+            }
+        }
+
+        // the account state must only be changed from a single state which is not created within
+        // the graph
+        let initial_states: BTreeSet<&Digest> = transitions
+            .keys()
+            .chain(non_transitions.keys())
+            .filter(|state| !final_states.contains_key(*state))
+            .collect();
+        let mut initial_states = initial_states.into_iter();
+        if let (Some(first), Some(second)) = (initial_states.next(), initial_states.next()) {
+            return Err(TransactionDependencyError::DisconnectedAccountStateTransitions {
+                account_id,
+                first: *first,
+                second: *second,
+            });
+        }
+    }
+
+    Ok(())
+}
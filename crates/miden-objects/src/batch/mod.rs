@@ -1,2 +1,8 @@
+mod dependency_graph;
+pub use dependency_graph::TransactionDependencyGraph;
+
 mod note_tree;
 pub use note_tree::BatchNoteTree;
+
+mod proposed_batch;
+pub use proposed_batch::ProposedBatch;
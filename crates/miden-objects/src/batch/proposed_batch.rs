@@ -0,0 +1,184 @@
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    vec::Vec,
+};
+
+use crate::{
+    errors::ProposedBatchError,
+    note::{NoteHeader, NoteId, NoteInclusionProof},
+    transaction::{ChainMmr, InputNoteCommitment, OutputNote, ProvenTransaction},
+    MAX_INPUT_NOTES_PER_BATCH, MAX_OUTPUT_NOTES_PER_BATCH,
+};
+
+// PROPOSED BATCH
+// ================================================================================================
+
+/// A set of proven transactions proposed for inclusion into a batch.
+///
+/// Notes created by one transaction of the batch and consumed as unauthenticated notes by another
+/// transaction of the same batch are erased from the batch, i.e. they are neither part of the
+/// batch's input notes nor of its output notes. All other unauthenticated input notes must be
+/// accompanied by a proof of their inclusion in the chain and are converted into authenticated
+/// input notes.
+#[derive(Debug, Clone)]
+pub struct ProposedBatch {
+    /// The transactions of this batch.
+    transactions: Vec<ProvenTransaction>,
+
+    /// The input notes of this batch, all of which are authenticated.
+    input_notes: Vec<InputNoteCommitment>,
+
+    /// The output notes of this batch which are not consumed within the batch.
+    output_notes: Vec<OutputNote>,
+
+    /// The headers of the notes which were both created and consumed within the batch.
+    erased_notes: Vec<NoteHeader>,
+}
+
+impl ProposedBatch {
+    /// Returns a new [ProposedBatch] instantiated from the provided transactions.
+    ///
+    /// Unauthenticated input notes which are not created by a transaction of the batch must be
+    /// authenticated with the inclusion proofs provided in `unauthenticated_note_proofs`. The
+    /// headers of the blocks referenced by these proofs must be contained in `chain_mmr`.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The provided list of transactions is empty.
+    /// - The same note is consumed more than once or created more than once within the batch.
+    /// - An unauthenticated input note is not created within the batch and does not have an
+    ///   inclusion proof, or the inclusion proof is not valid for any block in `chain_mmr`.
+    /// - The number of input or output notes of the batch exceeds the limits of a batch.
+    pub fn new(
+        transactions: Vec<ProvenTransaction>,
+        chain_mmr: &ChainMmr,
+        unauthenticated_note_proofs: &BTreeMap<NoteId, NoteInclusionProof>,
+    ) -> Result<Self, ProposedBatchError> {
+        if transactions.is_empty() {
+            return Err(ProposedBatchError::EmptyTransactionBatch);
+        }
+
+        // collect the notes created by all transactions of the batch
+        let mut output_notes = BTreeMap::new();
+        for note in transactions.iter().flat_map(|tx| tx.output_notes().iter()) {
+            if output_notes.insert(note.id(), note).is_some() {
+                return Err(ProposedBatchError::DuplicateOutputNote(note.id()));
+            }
+        }
+
+        // erase unauthenticated input notes which are created within the batch and authenticate
+        // the remaining ones
+        let mut nullifiers = BTreeSet::new();
+        let mut erased_notes = Vec::new();
+        let mut input_notes = Vec::new();
+        for input_note in transactions.iter().flat_map(|tx| tx.input_notes().iter()) {
+            if !nullifiers.insert(input_note.nullifier()) {
+                return Err(ProposedBatchError::DuplicateInputNote(input_note.nullifier()));
+            }
+
+            let Some(header) = input_note.header() else {
+                input_notes.push(input_note.clone());
+                continue;
+            };
+
+            let is_created_in_batch = output_notes
+                .get(&header.id())
+                .is_some_and(|output_note| output_note.hash() == header.hash());
+            if is_created_in_batch {
+                output_notes.remove(&header.id());
+                erased_notes.push(*header);
+                continue;
+            }
+
+            authenticate_note(header, chain_mmr, unauthenticated_note_proofs)?;
+            input_notes.push(InputNoteCommitment::from(input_note.nullifier()));
+        }
+
+        if input_notes.len() > MAX_INPUT_NOTES_PER_BATCH {
+            return Err(ProposedBatchError::TooManyInputNotes(input_notes.len()));
+        }
+
+        // preserve the order in which the transactions created the remaining output notes
+        let output_notes: Vec<OutputNote> = transactions
+            .iter()
+            .flat_map(|tx| tx.output_notes().iter())
+            .filter(|note| output_notes.contains_key(&note.id()))
+            .cloned()
+            .collect();
+
+        if output_notes.len() > MAX_OUTPUT_NOTES_PER_BATCH {
+            return Err(ProposedBatchError::TooManyOutputNotes(output_notes.len()));
+        }
+
+        Ok(Self {
+            transactions,
+            input_notes,
+            output_notes,
+            erased_notes,
+        })
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the transactions of this batch.
+    pub fn transactions(&self) -> &[ProvenTransaction] {
+        &self.transactions
+    }
+
+    /// Returns the input notes of this batch.
+    ///
+    /// All input notes are authenticated, i.e. they do not contain a note header. Notes which are
+    /// both created and consumed within the batch are not included.
+    pub fn input_notes(&self) -> &[InputNoteCommitment] {
+        &self.input_notes
+    }
+
+    /// Returns the output notes of this batch in the order in which they were created.
+    ///
+    /// Notes which are both created and consumed within the batch are not included.
+    pub fn output_notes(&self) -> &[OutputNote] {
+        &self.output_notes
+    }
+
+    /// Returns the headers of the notes which were both created and consumed within the batch.
+    pub fn erased_notes(&self) -> &[NoteHeader] {
+        &self.erased_notes
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Validates that the note with the provided header is included in the block referenced by its
+/// inclusion proof.
+fn authenticate_note(
+    header: &NoteHeader,
+    chain_mmr: &ChainMmr,
+    unauthenticated_note_proofs: &BTreeMap<NoteId, NoteInclusionProof>,
+) -> Result<(), ProposedBatchError> {
+    let proof = unauthenticated_note_proofs
+        .get(&header.id())
+        .ok_or(ProposedBatchError::UnauthenticatedNoteMissingInclusionProof(header.id()))?;
+
+    let block_num = proof.location().block_num();
+    let block_header = chain_mmr.get_block(block_num).ok_or(
+        ProposedBatchError::UnauthenticatedNoteBlockNotInChainMmr {
+            note_id: header.id(),
+            block_num,
+        },
+    )?;
+
+    proof
+        .note_path()
+        .verify(
+            proof.location().node_index_in_block().into(),
+            header.hash(),
+            &block_header.note_root(),
+        )
+        .map_err(|source| ProposedBatchError::UnauthenticatedNoteNotInBlock {
+            note_id: header.id(),
+            block_num,
+            source,
+        })
+}
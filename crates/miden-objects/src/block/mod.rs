@@ -15,7 +15,7 @@ pub use note_tree::{BlockNoteIndex, BlockNoteTree};
 use crate::{
     account::{delta::AccountUpdateDetails, AccountId},
     errors::BlockError,
-    note::Nullifier,
+    note::{Note, NoteId, NoteMetadata, Nullifier},
     transaction::{OutputNote, TransactionId},
     utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
 };
@@ -62,9 +62,15 @@ impl Block {
     /// Returns a new [Block] instantiated from the provided components.
     ///
     /// # Errors
-    /// Returns an error if block didn't pass validation.
+    /// Returns an error if:
+    /// - The number of account updates, note batches, notes or nullifiers exceeds the block limits.
+    /// - The block contains duplicate notes or nullifiers.
+    /// - An account is updated more than once or an account update does not reference any
+    ///   transactions.
+    /// - The transaction hash in the header does not match the hash computed from the account
+    ///   updates.
     ///
-    /// Note: consistency of the provided components is not validated.
+    /// Note: consistency of the provided components with the chain state is not validated.
     pub fn new(
         header: BlockHeader,
         updated_accounts: Vec<BlockAccountUpdate>,
@@ -150,6 +156,35 @@ impl Block {
         compute_tx_hash(self.transactions())
     }
 
+    /// Consumes this block and returns the updates which need to be applied to the chain state in
+    /// order to apply this block.
+    ///
+    /// See [BlockUpdates] for details.
+    pub fn into_updates(self) -> BlockUpdates {
+        let block_num = self.header.block_num();
+
+        let accounts = self
+            .updated_accounts
+            .into_iter()
+            .map(|update| (update.account_id, update.new_state_hash, update.details))
+            .collect();
+
+        let nullifiers =
+            self.nullifiers.into_iter().map(|nullifier| (nullifier, block_num)).collect();
+
+        let mut notes = Vec::new();
+        for (batch_idx, batch) in self.output_note_batches.into_iter().enumerate() {
+            for (note_idx_in_batch, note) in batch.into_iter().enumerate() {
+                let note_index = BlockNoteIndex::new(batch_idx, note_idx_in_batch).expect(
+                    "Something went wrong: block is invalid, but passed or skipped validation",
+                );
+                notes.push(BlockNoteUpdate::new(note_index, note));
+            }
+        }
+
+        BlockUpdates { accounts, nullifiers, notes }
+    }
+
     // HELPER METHODS
     // --------------------------------------------------------------------------------------------
 
@@ -171,6 +206,31 @@ impl Block {
             return Err(BlockError::TooManyNullifiersInBlock(nullifier_count));
         }
 
+        let mut nullifiers = BTreeSet::new();
+        for nullifier in self.nullifiers.iter() {
+            if !nullifiers.insert(nullifier) {
+                return Err(BlockError::DuplicateNullifier(*nullifier));
+            }
+        }
+
+        let mut updated_accounts = BTreeSet::new();
+        for update in self.updated_accounts.iter() {
+            if !updated_accounts.insert(update.account_id()) {
+                return Err(BlockError::DuplicateAccountUpdate(update.account_id()));
+            }
+            if update.transactions().is_empty() {
+                return Err(BlockError::EmptyAccountUpdateTransactions(update.account_id()));
+            }
+        }
+
+        let computed_tx_hash = self.compute_tx_hash();
+        if self.header.tx_hash() != computed_tx_hash {
+            return Err(BlockError::TxHashMismatch {
+                header_tx_hash: self.header.tx_hash(),
+                computed_tx_hash,
+            });
+        }
+
         let mut output_notes = BTreeSet::new();
         let mut output_note_count = 0;
         for batch in self.output_note_batches.iter() {
@@ -222,6 +282,109 @@ impl Deserializable for Block {
     }
 }
 
+// BLOCK UPDATES
+// ================================================================================================
+
+/// The updates of the chain state resulting from applying a [Block], as returned by
+/// [Block::into_updates].
+///
+/// The updates consist of:
+/// - accounts: the ID, the new state hash and the update details of all accounts updated in the
+///   block.
+/// - nullifiers: the nullifiers of all notes consumed in the block, together with the number of the
+///   block.
+/// - notes: the ID, metadata and index in the block's note tree of all notes created in the block,
+///   together with the full note if the block contains the note's details.
+#[derive(Debug, Clone)]
+pub struct BlockUpdates {
+    accounts: Vec<(AccountId, Digest, AccountUpdateDetails)>,
+    nullifiers: Vec<(Nullifier, BlockNumber)>,
+    notes: Vec<BlockNoteUpdate>,
+}
+
+impl BlockUpdates {
+    /// Returns the ID, the new state hash and the update details of all accounts updated in the
+    /// block.
+    pub fn accounts(&self) -> &[(AccountId, Digest, AccountUpdateDetails)] {
+        &self.accounts
+    }
+
+    /// Returns the nullifiers of all notes consumed in the block, together with the number of the
+    /// block.
+    pub fn nullifiers(&self) -> &[(Nullifier, BlockNumber)] {
+        &self.nullifiers
+    }
+
+    /// Returns the updates of all notes created in the block, in the order of their indices in the
+    /// block's note tree.
+    pub fn notes(&self) -> &[BlockNoteUpdate] {
+        &self.notes
+    }
+
+    /// Consumes these updates and returns the account, nullifier and note updates.
+    #[allow(clippy::type_complexity)]
+    pub fn into_parts(
+        self,
+    ) -> (
+        Vec<(AccountId, Digest, AccountUpdateDetails)>,
+        Vec<(Nullifier, BlockNumber)>,
+        Vec<BlockNoteUpdate>,
+    ) {
+        (self.accounts, self.nullifiers, self.notes)
+    }
+}
+
+/// A note created in a block, as contained in [BlockUpdates].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlockNoteUpdate {
+    note_id: NoteId,
+    metadata: NoteMetadata,
+    note_index: BlockNoteIndex,
+    note: Option<Note>,
+}
+
+impl BlockNoteUpdate {
+    /// Returns a new [BlockNoteUpdate] for the provided note located at the specified index of the
+    /// block's note tree.
+    ///
+    /// The full note is only retained for [OutputNote::Full] notes.
+    pub fn new(note_index: BlockNoteIndex, note: OutputNote) -> Self {
+        let note_id = note.id();
+        let metadata = *note.metadata();
+        let note = match note {
+            OutputNote::Full(note) => Some(note),
+            OutputNote::Partial(_) | OutputNote::Header(_) => None,
+        };
+
+        Self { note_id, metadata, note_index, note }
+    }
+
+    /// Returns the ID of the note.
+    pub fn note_id(&self) -> NoteId {
+        self.note_id
+    }
+
+    /// Returns the metadata of the note.
+    pub fn metadata(&self) -> &NoteMetadata {
+        &self.metadata
+    }
+
+    /// Returns the index of the note in the block's note tree.
+    pub fn note_index(&self) -> BlockNoteIndex {
+        self.note_index
+    }
+
+    /// Returns the full note, if the block contains the note's details.
+    pub fn note(&self) -> Option<&Note> {
+        self.note.as_ref()
+    }
+
+    /// Consumes this update and returns the full note, if the block contains the note's details.
+    pub fn into_note(self) -> Option<Note> {
+        self.note
+    }
+}
+
 // TRANSACTION HASH COMPUTATION
 // ================================================================================================
 
@@ -325,3 +488,203 @@ impl Deserializable for BlockAccountUpdate {
         })
     }
 }
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use assembly::Assembler;
+    use assert_matches::assert_matches;
+    use rand::SeedableRng;
+    use rand_xoshiro::Xoshiro256PlusPlus;
+
+    use super::*;
+    use crate::{
+        testing::{
+            account_id::{
+                ACCOUNT_ID_OFF_CHAIN_SENDER, ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN,
+            },
+            note::NoteBuilder,
+        },
+        utils::serde::{CompressedSerializable, COMPRESSED_ENCODING_VERSION},
+    };
+
+    fn header_with_tx_hash(tx_hash: Digest) -> BlockHeader {
+        BlockHeader::new(
+            0,
+            Digest::default(),
+            BlockNumber::from(1),
+            Digest::default(),
+            Digest::default(),
+            Digest::default(),
+            Digest::default(),
+            tx_hash,
+            Digest::default(),
+            Digest::default(),
+            0,
+        )
+    }
+
+    fn account_update(account_id: u128, transactions: Vec<TransactionId>) -> BlockAccountUpdate {
+        BlockAccountUpdate::new(
+            AccountId::try_from(account_id).unwrap(),
+            Digest::default(),
+            AccountUpdateDetails::Private,
+            transactions,
+        )
+    }
+
+    fn build_block(
+        updated_accounts: Vec<BlockAccountUpdate>,
+        nullifiers: Vec<Nullifier>,
+    ) -> Result<Block, BlockError> {
+        build_block_with_notes(updated_accounts, vec![], nullifiers)
+    }
+
+    fn build_block_with_notes(
+        updated_accounts: Vec<BlockAccountUpdate>,
+        output_note_batches: Vec<NoteBatch>,
+        nullifiers: Vec<Nullifier>,
+    ) -> Result<Block, BlockError> {
+        let tx_hash = compute_tx_hash(updated_accounts.iter().flat_map(|update| {
+            update.transactions().iter().map(|tx_id| (*tx_id, update.account_id()))
+        }));
+
+        Block::new(header_with_tx_hash(tx_hash), updated_accounts, output_note_batches, nullifiers)
+    }
+
+    #[test]
+    fn block_validation() {
+        let tx_1 = TransactionId::from(Digest::from([Felt::new(1); 4]));
+        let tx_2 = TransactionId::from(Digest::from([Felt::new(2); 4]));
+        let nullifier_1 = Nullifier::from(Digest::from([Felt::new(3); 4]));
+        let nullifier_2 = Nullifier::from(Digest::from([Felt::new(4); 4]));
+
+        let block = build_block(
+            vec![
+                account_update(ACCOUNT_ID_OFF_CHAIN_SENDER, vec![tx_1]),
+                account_update(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN, vec![tx_2]),
+            ],
+            vec![nullifier_1, nullifier_2],
+        )
+        .unwrap();
+        assert_eq!(Block::read_from_bytes(&block.to_bytes()).unwrap().hash(), block.hash());
+
+        assert_matches!(
+            build_block(vec![], vec![nullifier_1, nullifier_2, nullifier_1]),
+            Err(BlockError::DuplicateNullifier(nullifier)) if nullifier == nullifier_1
+        );
+
+        let account_id = AccountId::try_from(ACCOUNT_ID_OFF_CHAIN_SENDER).unwrap();
+        assert_matches!(
+            build_block(
+                vec![
+                    account_update(ACCOUNT_ID_OFF_CHAIN_SENDER, vec![tx_1]),
+                    account_update(ACCOUNT_ID_OFF_CHAIN_SENDER, vec![tx_2]),
+                ],
+                vec![],
+            ),
+            Err(BlockError::DuplicateAccountUpdate(id)) if id == account_id
+        );
+        assert_matches!(
+            build_block(vec![account_update(ACCOUNT_ID_OFF_CHAIN_SENDER, vec![])], vec![]),
+            Err(BlockError::EmptyAccountUpdateTransactions(id)) if id == account_id
+        );
+
+        let updated_accounts = vec![account_update(ACCOUNT_ID_OFF_CHAIN_SENDER, vec![tx_1])];
+        assert_matches!(
+            Block::new(header_with_tx_hash(Digest::default()), updated_accounts, vec![], vec![]),
+            Err(BlockError::TxHashMismatch { .. })
+        );
+    }
+
+    #[test]
+    fn block_into_updates() {
+        let tx_1 = TransactionId::from(Digest::from([Felt::new(1); 4]));
+        let nullifier_1 = Nullifier::from(Digest::from([Felt::new(3); 4]));
+        let nullifier_2 = Nullifier::from(Digest::from([Felt::new(4); 4]));
+
+        let sender = AccountId::try_from(ACCOUNT_ID_OFF_CHAIN_SENDER).unwrap();
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(0);
+        let assembler = Assembler::default();
+        let note_1 = NoteBuilder::new(sender, &mut rng).build(&assembler).unwrap();
+        let note_2 = NoteBuilder::new(sender, &mut rng).build(&assembler).unwrap();
+        let note_3 = NoteBuilder::new(sender, &mut rng).build(&assembler).unwrap();
+
+        let block = build_block_with_notes(
+            vec![account_update(ACCOUNT_ID_OFF_CHAIN_SENDER, vec![tx_1])],
+            vec![
+                vec![OutputNote::Full(note_1.clone()), OutputNote::Header(*note_2.header())],
+                vec![OutputNote::Full(note_3.clone())],
+            ],
+            vec![nullifier_1, nullifier_2],
+        )
+        .unwrap();
+        let block_num = block.header().block_num();
+        let note_indices: Vec<BlockNoteIndex> = block.notes().map(|(index, _)| index).collect();
+
+        let (accounts, nullifiers, notes) = block.into_updates().into_parts();
+
+        assert_eq!(accounts, [(sender, Digest::default(), AccountUpdateDetails::Private)]);
+        assert_eq!(nullifiers, [(nullifier_1, block_num), (nullifier_2, block_num)]);
+
+        assert_eq!(notes.len(), 3);
+        for ((update, note_index), (note_id, metadata)) in notes.iter().zip(note_indices).zip([
+            (note_1.id(), *note_1.metadata()),
+            (note_2.id(), *note_2.metadata()),
+            (note_3.id(), *note_3.metadata()),
+        ]) {
+            assert_eq!(update.note_id(), note_id);
+            assert_eq!(update.metadata(), &metadata);
+            assert_eq!(update.note_index(), note_index);
+        }
+        assert_eq!(notes[0].note(), Some(&note_1));
+        assert_eq!(notes[1].note(), None);
+        assert_eq!(notes[2].note(), Some(&note_3));
+    }
+
+    #[test]
+    fn block_compressed_serialization() {
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(0);
+        let sender = AccountId::try_from(ACCOUNT_ID_OFF_CHAIN_SENDER).unwrap();
+        let assembler = Assembler::default();
+        let notes = (0..8)
+            .map(|_| {
+                OutputNote::Full(NoteBuilder::new(sender, &mut rng).build(&assembler).unwrap())
+            })
+            .collect();
+
+        let block = build_block_with_notes(
+            vec![account_update(
+                ACCOUNT_ID_OFF_CHAIN_SENDER,
+                vec![TransactionId::from(Digest::from([Felt::new(1); 4]))],
+            )],
+            vec![notes],
+            vec![],
+        )
+        .unwrap();
+
+        let compressed = block.to_compressed_bytes();
+        assert!(compressed.len() < block.to_bytes().len());
+        assert_eq!(
+            Block::read_from_compressed_bytes(&compressed).unwrap().to_bytes(),
+            block.to_bytes()
+        );
+
+        // the compressed encoding cannot be read as the regular encoding and vice versa
+        assert!(Block::read_from_bytes(&compressed).is_err());
+        assert!(Block::read_from_compressed_bytes(&block.to_bytes()).is_err());
+
+        // unsupported versions are rejected
+        let mut invalid_version = compressed.clone();
+        invalid_version[4] = COMPRESSED_ENCODING_VERSION + 1;
+        assert_matches!(
+            Block::read_from_compressed_bytes(&invalid_version),
+            Err(DeserializationError::InvalidValue(_))
+        );
+
+        // truncated payloads are rejected
+        assert!(Block::read_from_compressed_bytes(&compressed[..compressed.len() - 1]).is_err());
+    }
+}
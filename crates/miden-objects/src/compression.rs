@@ -0,0 +1,257 @@
+use alloc::{string::ToString, vec::Vec};
+use std::io::Read;
+
+use crate::{
+    block::Block,
+    transaction::{ProvenTransaction, TransactionWitness},
+    utils::{
+        serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+        SliceReader,
+    },
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// The magic bytes at the start of the header of a compressed encoding.
+const MAGIC: [u8; 4] = *b"MZST";
+
+/// The version of the compressed encoding written by
+/// [CompressedSerializable::write_into_compressed].
+pub const COMPRESSED_ENCODING_VERSION: u8 = 1;
+
+/// The maximum length of the regular encoding of an object read from the compressed encoding.
+///
+/// This bounds the amount of data decompressed from an untrusted payload and leaves ample room for
+/// the largest blocks and proven transactions.
+pub const MAX_UNCOMPRESSED_LENGTH: usize = 1 << 26;
+
+/// The zstd compression level used by [CompressedSerializable::write_into_compressed].
+const COMPRESSION_LEVEL: i32 = zstd::DEFAULT_COMPRESSION_LEVEL;
+
+// COMPRESSED SERIALIZABLE
+// ================================================================================================
+
+/// Defines a zstd-compressed encoding for large objects, e.g. blocks and proven transactions with
+/// many public notes.
+///
+/// The compressed encoding consists of the following header, followed by the zstd-compressed
+/// regular encoding of the object:
+/// - magic bytes: `MZST`.
+/// - version: the version of the compressed encoding (see [COMPRESSED_ENCODING_VERSION]).
+/// - uncompressed length: the length of the regular encoding of the object.
+/// - compressed length: the length of the compressed payload.
+pub trait CompressedSerializable: Serializable + Deserializable {
+    /// Serializes `self` into the provided target using the compressed encoding.
+    fn write_into_compressed<W: ByteWriter>(&self, target: &mut W) {
+        let bytes = self.to_bytes();
+        let compressed = zstd::bulk::compress(&bytes, COMPRESSION_LEVEL)
+            .expect("compressing an in-memory buffer should not fail");
+
+        target.write_bytes(&MAGIC);
+        target.write_u8(COMPRESSED_ENCODING_VERSION);
+        target.write_usize(bytes.len());
+        target.write_usize(compressed.len());
+        target.write_bytes(&compressed);
+    }
+
+    /// Serializes `self` into a vector of bytes using the compressed encoding.
+    fn to_compressed_bytes(&self) -> Vec<u8> {
+        let mut target = Vec::new();
+        self.write_into_compressed(&mut target);
+        target
+    }
+
+    /// Reads an object encoded using the compressed encoding from the provided source.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The header is malformed or specifies an unsupported version.
+    /// - The uncompressed length exceeds [MAX_UNCOMPRESSED_LENGTH].
+    /// - The payload cannot be decompressed or its length does not match the one in the header.
+    /// - The decompressed payload is not a valid encoding of the object.
+    fn read_from_compressed<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let magic: [u8; 4] = source.read_array()?;
+        if magic != MAGIC {
+            return Err(DeserializationError::InvalidValue(
+                "compressed encoding header does not start with the expected magic bytes"
+                    .to_string(),
+            ));
+        }
+
+        let version = source.read_u8()?;
+        if version != COMPRESSED_ENCODING_VERSION {
+            return Err(DeserializationError::InvalidValue(format!(
+                "unsupported compressed encoding version {version}, expected version {COMPRESSED_ENCODING_VERSION}"
+            )));
+        }
+
+        let uncompressed_len = source.read_usize()?;
+        if uncompressed_len > MAX_UNCOMPRESSED_LENGTH {
+            return Err(DeserializationError::InvalidValue(format!(
+                "uncompressed length {uncompressed_len} exceeds the maximum of {MAX_UNCOMPRESSED_LENGTH}"
+            )));
+        }
+        let compressed_len = source.read_usize()?;
+        let compressed = source.read_slice(compressed_len)?;
+
+        // decompress at most one byte more than the header specifies, so that payloads which are
+        // longer than specified are detected without decompressing them in full
+        let mut bytes = Vec::new();
+        zstd::Decoder::with_buffer(compressed)
+            .and_then(|decoder| decoder.take(uncompressed_len as u64 + 1).read_to_end(&mut bytes))
+            .map_err(|err| {
+                DeserializationError::InvalidValue(format!("failed to decompress payload: {err}"))
+            })?;
+        if bytes.len() != uncompressed_len {
+            return Err(DeserializationError::InvalidValue(format!(
+                "decompressed payload length does not match the length {uncompressed_len} specified in the header"
+            )));
+        }
+
+        Self::read_from_bytes(&bytes)
+    }
+
+    /// Reads an object encoded using the compressed encoding from the provided bytes.
+    ///
+    /// # Errors
+    /// Returns an error if the bytes are not a valid compressed encoding of the object, or if not
+    /// all bytes were consumed.
+    fn read_from_compressed_bytes(bytes: &[u8]) -> Result<Self, DeserializationError> {
+        let mut source = SliceReader::new(bytes);
+        let object = Self::read_from_compressed(&mut source)?;
+        if source.has_more_bytes() {
+            return Err(DeserializationError::UnconsumedBytes);
+        }
+
+        Ok(object)
+    }
+}
+
+impl CompressedSerializable for Block {}
+
+impl CompressedSerializable for ProvenTransaction {}
+
+impl CompressedSerializable for TransactionWitness {}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+
+    use super::*;
+    use crate::{
+        account::{Account, AccountCode, AccountId, AccountStorage},
+        asset::AssetVault,
+        block::BlockHeader,
+        crypto::merkle::{Mmr, PartialMmr},
+        testing::account_id::ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
+        transaction::{ChainMmr, InputNotes, TransactionArgs, TransactionInputs},
+        vm::AdviceInputs,
+        Digest, ONE,
+    };
+
+    fn mock_tx_witness() -> TransactionWitness {
+        let account = Account::from_parts(
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap(),
+            AssetVault::default(),
+            AccountStorage::new(vec![]).unwrap(),
+            AccountCode::mock(),
+            ONE,
+        );
+        let partial_mmr: PartialMmr = Mmr::default().peaks().into();
+        let chain_mmr = ChainMmr::new(partial_mmr, Vec::new()).unwrap();
+        let chain_root = chain_mmr.peaks().hash_peaks();
+        let block_header =
+            BlockHeader::mock(0, Some(chain_root), None, &[account.clone()], Digest::default());
+        let account_codes = vec![account.code().clone()];
+        let tx_inputs =
+            TransactionInputs::new(account, None, block_header, chain_mmr, InputNotes::default())
+                .unwrap();
+
+        TransactionWitness {
+            tx_inputs,
+            tx_args: TransactionArgs::default(),
+            advice_witness: AdviceInputs::default(),
+            account_codes,
+        }
+    }
+
+    /// Returns the compressed encoding of `object` with its uncompressed length in the header
+    /// replaced by `uncompressed_len`.
+    fn with_uncompressed_len<T: CompressedSerializable>(
+        object: &T,
+        uncompressed_len: usize,
+    ) -> Vec<u8> {
+        let bytes = object.to_bytes();
+        let compressed = zstd::bulk::compress(&bytes, COMPRESSION_LEVEL).unwrap();
+
+        let mut target = Vec::new();
+        target.write_bytes(&MAGIC);
+        target.write_u8(COMPRESSED_ENCODING_VERSION);
+        target.write_usize(uncompressed_len);
+        target.write_usize(compressed.len());
+        target.write_bytes(&compressed);
+        target
+    }
+
+    #[test]
+    fn tx_witness_compressed_serialization() {
+        let witness = mock_tx_witness();
+        let compressed = witness.to_compressed_bytes();
+        assert!(compressed.len() < witness.to_bytes().len());
+        assert_eq!(TransactionWitness::read_from_compressed_bytes(&compressed).unwrap(), witness);
+    }
+
+    #[test]
+    fn compressed_header_is_validated() {
+        let witness = mock_tx_witness();
+        let compressed = witness.to_compressed_bytes();
+
+        // invalid magic bytes are rejected
+        let mut invalid_magic = compressed.clone();
+        invalid_magic[0] = b'X';
+        assert_matches!(
+            TransactionWitness::read_from_compressed_bytes(&invalid_magic),
+            Err(DeserializationError::InvalidValue(_))
+        );
+
+        // uncompressed lengths above the maximum are rejected
+        let too_long = with_uncompressed_len(&witness, MAX_UNCOMPRESSED_LENGTH + 1);
+        assert_matches!(
+            TransactionWitness::read_from_compressed_bytes(&too_long),
+            Err(DeserializationError::InvalidValue(_))
+        );
+    }
+
+    #[test]
+    fn compressed_length_mismatch_is_rejected() {
+        let witness = mock_tx_witness();
+        let len = witness.to_bytes().len();
+        assert_eq!(
+            TransactionWitness::read_from_compressed_bytes(&with_uncompressed_len(&witness, len))
+                .unwrap(),
+            witness
+        );
+
+        // the payload is longer than the length specified in the header
+        assert_matches!(
+            TransactionWitness::read_from_compressed_bytes(&with_uncompressed_len(
+                &witness,
+                len - 1
+            )),
+            Err(DeserializationError::InvalidValue(_))
+        );
+
+        // the payload is shorter than the length specified in the header
+        assert_matches!(
+            TransactionWitness::read_from_compressed_bytes(&with_uncompressed_len(
+                &witness,
+                len + 1
+            )),
+            Err(DeserializationError::InvalidValue(_))
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use alloc::{boxed::Box, string::String};
+use alloc::{boxed::Box, string::String, vec::Vec};
 use core::error::Error;
 
 use assembly::{diagnostics::reporting::PrintDiagnostic, Report};
@@ -12,8 +12,8 @@ use super::{
     asset::{FungibleAsset, NonFungibleAsset},
     crypto::merkle::MerkleError,
     note::NoteId,
-    Digest, Word, MAX_ACCOUNTS_PER_BLOCK, MAX_BATCHES_PER_BLOCK, MAX_INPUT_NOTES_PER_BLOCK,
-    MAX_OUTPUT_NOTES_PER_BATCH, MAX_OUTPUT_NOTES_PER_BLOCK,
+    Digest, Word, MAX_ACCOUNTS_PER_BLOCK, MAX_BATCHES_PER_BLOCK, MAX_INPUT_NOTES_PER_BATCH,
+    MAX_INPUT_NOTES_PER_BLOCK, MAX_OUTPUT_NOTES_PER_BATCH, MAX_OUTPUT_NOTES_PER_BLOCK,
 };
 use crate::{
     account::{
@@ -22,7 +22,9 @@ use crate::{
     },
     block::BlockNumber,
     note::{NoteAssets, NoteExecutionHint, NoteTag, NoteType, Nullifier},
+    transaction::TransactionId,
     ACCOUNT_UPDATE_MAX_SIZE, MAX_INPUTS_PER_NOTE, MAX_INPUT_NOTES_PER_TX, MAX_OUTPUT_NOTES_PER_TX,
+    WORD_SIZE,
 };
 
 // ACCOUNT COMPONENT TEMPLATE ERROR
@@ -64,6 +66,10 @@ pub enum AccountError {
     AccountCodeDeserializationError(#[source] DeserializationError),
     #[error("account code does not contain procedures but must contain at least one procedure")]
     AccountCodeNoProcedures,
+    #[error(
+        "code of account {0} cannot be upgraded because the account does not have updatable code"
+    )]
+    AccountCodeNotUpdatable(AccountId),
     #[error("account code contains {0} procedures but it may contain at most {max} procedures", max = AccountCode::MAX_NUM_PROCEDURES)]
     AccountCodeTooManyProcedures(usize),
     #[error("account procedure {0}'s storage offset {1} does not fit into u8")]
@@ -82,18 +88,34 @@ pub enum AccountError {
     AccountComponentTemplateInstantiationError(#[source] AccountComponentTemplateError),
     #[error("failed to update asset vault")]
     AssetVaultUpdateError(#[source] AssetVaultError),
+    #[error("blob has {actual} bytes which exceeds the maximum blob size of {max} bytes")]
+    BlobStorageBlobTooLarge { actual: usize, max: usize },
+    #[error("blob of {byte_len} bytes cannot be decoded from {num_chunks} chunks")]
+    BlobStorageChunkCountMismatch { byte_len: usize, num_chunks: usize },
+    #[error("stored blob commitment {expected} does not match the commitment {actual} of the stored chunks")]
+    BlobStorageCommitmentMismatch { expected: Digest, actual: Digest },
+    #[error("blob chunk element {0} is not a valid u32 value")]
+    BlobStorageInvalidChunkElement(Felt),
+    #[error("blob has {actual} chunks which exceeds the maximum number of {max} chunks")]
+    BlobStorageTooManyChunks { actual: u64, max: usize },
     #[error("account build error: {0}")]
     BuildError(String, #[source] Option<Box<AccountError>>),
     #[error("faucet metadata decimals is {actual} which exceeds max value of {max}")]
     FungibleFaucetTooManyDecimals { actual: u8, max: u8 },
     #[error("faucet metadata max supply is {actual} which exceeds max value of {max}")]
     FungibleFaucetMaxSupplyTooLarge { actual: u64, max: u64 },
+    #[error("faucet recipient mint cap is {actual} but it must be non-zero and at most {max}")]
+    FungibleFaucetInvalidRecipientMintCap { actual: u64, max: u64 },
     #[error("account header data has length {actual} but it must be of length {expected}")]
     HeaderDataIncorrectLength { actual: usize, expected: usize },
     #[error("new account nonce {new} is less than the current nonce {current}")]
     NonceNotMonotonicallyIncreasing { current: u64, new: u64 },
     #[error("digest of the seed has {actual} trailing zeroes but must have at least {expected} trailing zeroes")]
     SeedDigestTooFewTrailingZeros { expected: u32, actual: u32 },
+    #[error("price of a price oracle entry must be non-zero")]
+    PriceOracleZeroPrice,
+    #[error("word {0:?} is not a valid price oracle entry")]
+    PriceOracleInvalidPriceEntry(Word),
     #[error("storage slot at index {0} is not of type map")]
     StorageSlotNotMap(u8),
     #[error("storage slot at index {0} is not of type value")]
@@ -290,12 +312,43 @@ pub enum NoteError {
     NoteScriptDeserializationError(#[source] DeserializationError),
     #[error("public use case requires a public note but note is of type {0:?}")]
     PublicUseCaseRequiresPublicNote(NoteType),
+    #[error("recipient digest {actual} does not match the expected recipient digest {expected}")]
+    RecipientDigestMismatch { expected: Digest, actual: Digest },
     #[error("note contains {0} assets which exceeds the maximum of {max}", max = NoteAssets::MAX_NUM_ASSETS)]
     TooManyAssets(usize),
     #[error("note contains {0} inputs which exceeds the maximum of {max}", max = MAX_INPUTS_PER_NOTE)]
     TooManyInputs(usize),
 }
 
+// NOTE SCRIPT SCHEMA ERROR
+// ================================================================================================
+
+#[derive(Debug, Error)]
+pub enum NoteScriptSchemaError {
+    #[error("note args element at index {0} cannot be declared as a part of an account ID")]
+    AccountIdArg(usize),
+    #[error("note args index {0} is out of bounds 0..{max}", max = WORD_SIZE)]
+    ArgIndexOutOfBounds(usize),
+    #[error("note args element at index {index} is expected to be a u32 but has value {value}")]
+    ArgNotU32 { index: usize, value: Felt },
+    #[error("note args element at index {0} is declared more than once")]
+    DuplicateArg(usize),
+    #[error("note input at index {index} is expected to be a u32 but has value {value}")]
+    InputNotU32 { index: usize, value: Felt },
+    #[error("note script expects {expected} note inputs but {actual} were provided")]
+    InputsCountMismatch { expected: usize, actual: usize },
+    #[error("note inputs starting at index {index} do not form a valid account ID")]
+    InvalidAccountIdInputs { index: usize, source: AccountIdError },
+    #[error("note script schema declares {0} inputs which exceeds the maximum of {max}", max = MAX_INPUTS_PER_NOTE)]
+    TooManyInputs(usize),
+    #[error(
+        "note input at index {0} is not part of a pair of account ID suffix and prefix inputs"
+    )]
+    UnpairedAccountIdInput(usize),
+    #[error("note args element at index {index} is not used by the note script but has non-zero value {value}")]
+    UndeclaredArg { index: usize, value: Felt },
+}
+
 // CHAIN MMR ERROR
 // ================================================================================================
 
@@ -387,6 +440,10 @@ pub enum TransactionOutputError {
     OutputNotesCommitmentInconsistent { expected: Digest, actual: Digest },
     #[error("transaction kernel output stack is invalid: {0}")]
     OutputStackInvalid(String),
+    #[error("transaction kernel output stack contains non-zero element {value} at index {index} which is expected to be zero")]
+    OutputStackOverflowNotZero { index: usize, value: Felt },
+    #[error("transaction kernel output stack is too short to contain an element at index {0}")]
+    OutputStackTooShort(usize),
     #[error("total number of output notes is {0} which exceeds the maximum of {MAX_OUTPUT_NOTES_PER_TX}")]
     TooManyOutputNotes(usize),
 }
@@ -418,6 +475,10 @@ pub enum ProvenTransactionError {
         "existing on-chain account {0} should only provide delta updates instead of full details"
     )]
     ExistingOnChainAccountRequiresDeltaDetails(AccountId),
+    #[error("code of on-chain account {0} cannot be upgraded because the account does not have updatable code")]
+    AccountCodeNotUpdatable(AccountId),
+    #[error("code upgrade of on-chain account {0} must increment the account nonce")]
+    AccountCodeUpgradeWithoutNonceIncrement(AccountId),
     #[error("failed to construct output notes for proven transaction")]
     OutputNotesError(TransactionOutputError),
     #[error(
@@ -429,13 +490,83 @@ pub enum ProvenTransactionError {
     },
 }
 
+// PROPOSED BATCH ERROR
+// ================================================================================================
+
+#[derive(Debug, Error)]
+pub enum ProposedBatchError {
+    #[error("note with nullifier {0} is consumed more than once in the batch")]
+    DuplicateInputNote(Nullifier),
+    #[error("note with id {0} is created more than once in the batch")]
+    DuplicateOutputNote(NoteId),
+    #[error("batch must contain at least one transaction")]
+    EmptyTransactionBatch,
+    #[error("too many input notes in the batch (max: {MAX_INPUT_NOTES_PER_BATCH}, actual: {0})")]
+    TooManyInputNotes(usize),
+    #[error("too many output notes in the batch (max: {MAX_OUTPUT_NOTES_PER_BATCH}, actual: {0})")]
+    TooManyOutputNotes(usize),
+    #[error("block {block_num} referenced by the inclusion proof of unauthenticated note {note_id} is not in the chain MMR")]
+    UnauthenticatedNoteBlockNotInChainMmr { note_id: NoteId, block_num: BlockNumber },
+    #[error(
+        "unauthenticated note {0} is not created in the batch and is missing an inclusion proof"
+    )]
+    UnauthenticatedNoteMissingInclusionProof(NoteId),
+    #[error(
+        "inclusion proof of unauthenticated note {note_id} is not valid for block {block_num}"
+    )]
+    UnauthenticatedNoteNotInBlock {
+        note_id: NoteId,
+        block_num: BlockNumber,
+        source: MerkleError,
+    },
+}
+
+// TRANSACTION DEPENDENCY ERROR
+// ================================================================================================
+
+#[derive(Debug, Error)]
+pub enum TransactionDependencyError {
+    #[error("transactions {first} and {second} both change account {account_id} from state {initial_state}")]
+    ConflictingAccountStateTransitions {
+        account_id: AccountId,
+        initial_state: Digest,
+        first: TransactionId,
+        second: TransactionId,
+    },
+    #[error("dependencies of transactions {0:?} form a cycle")]
+    DependencyCycle(Vec<TransactionId>),
+    #[error("state transitions of account {account_id} start from both state {first} and state {second}")]
+    DisconnectedAccountStateTransitions {
+        account_id: AccountId,
+        first: Digest,
+        second: Digest,
+    },
+    #[error("note with nullifier {0} is consumed more than once")]
+    DuplicateInputNote(Nullifier),
+    #[error("note with id {0} is created more than once")]
+    DuplicateOutputNote(NoteId),
+    #[error("transaction {0} is provided more than once")]
+    DuplicateTransaction(TransactionId),
+}
+
 // BLOCK VALIDATION ERROR
 // ================================================================================================
 
 #[derive(Debug, Error)]
 pub enum BlockError {
+    #[error("account {0} is updated more than once in the block")]
+    DuplicateAccountUpdate(AccountId),
     #[error("duplicate note with id {0} in the block")]
     DuplicateNoteFound(NoteId),
+    #[error("duplicate nullifier {0} in the block")]
+    DuplicateNullifier(Nullifier),
+    #[error("update of account {0} in the block does not reference any transactions")]
+    EmptyAccountUpdateTransactions(AccountId),
+    #[error("transaction hash {header_tx_hash} in the block header does not match the transaction hash {computed_tx_hash} computed from the account updates")]
+    TxHashMismatch {
+        header_tx_hash: Digest,
+        computed_tx_hash: Digest,
+    },
     #[error("too many accounts updated in the block (max: {MAX_ACCOUNTS_PER_BLOCK}, actual: {0})")]
     TooManyAccountUpdates(usize),
     #[error("too many notes in the batch (max: {MAX_OUTPUT_NOTES_PER_BATCH}, actual: {0})")]
@@ -16,6 +16,8 @@ pub mod transaction;
 #[cfg(any(feature = "testing", test))]
 pub mod testing;
 
+#[cfg(feature = "compression")]
+mod compression;
 mod constants;
 mod errors;
 
@@ -25,8 +27,9 @@ mod errors;
 pub use constants::*;
 pub use errors::{
     AccountDeltaError, AccountError, AccountIdError, AssetError, AssetVaultError, BlockError,
-    ChainMmrError, NoteError, ProvenTransactionError, TransactionInputError,
-    TransactionOutputError, TransactionScriptError,
+    ChainMmrError, NoteError, NoteScriptSchemaError, ProposedBatchError, ProvenTransactionError,
+    TransactionDependencyError, TransactionInputError, TransactionOutputError,
+    TransactionScriptError,
 };
 pub use miden_crypto::hash::rpo::{Rpo256 as Hasher, RpoDigest as Digest};
 pub use vm_core::{Felt, FieldElement, StarkField, Word, EMPTY_WORD, ONE, WORD_SIZE, ZERO};
@@ -34,7 +37,7 @@ pub use vm_core::{Felt, FieldElement, StarkField, Word, EMPTY_WORD, ONE, WORD_SI
 pub mod assembly {
     pub use assembly::{
         mast, Assembler, AssemblyError, DefaultSourceManager, KernelLibrary, Library,
-        LibraryNamespace, LibraryPath, SourceManager, Version,
+        LibraryNamespace, LibraryPath, Report, SourceManager, Version,
     };
 }
 
@@ -43,7 +46,10 @@ pub mod crypto {
 }
 
 pub mod utils {
-    use alloc::string::{String, ToString};
+    use alloc::{
+        string::{String, ToString},
+        vec::Vec,
+    };
 
     pub use miden_crypto::utils::{bytes_to_hex_string, collections, hex_to_bytes, HexParseError};
     pub use vm_core::utils::*;
@@ -53,6 +59,11 @@ pub mod utils {
         pub use miden_crypto::utils::{
             ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable,
         };
+
+        #[cfg(feature = "compression")]
+        pub use crate::compression::{
+            CompressedSerializable, COMPRESSED_ENCODING_VERSION, MAX_UNCOMPRESSED_LENGTH,
+        };
     }
 
     pub const fn parse_hex_string_as_word(hex: &str) -> Result<[Felt; 4], &'static str> {
@@ -134,6 +145,11 @@ pub mod utils {
         }
     }
 
+    /// Converts a word to MASM
+    pub fn prepare_word(word: &[Felt; 4]) -> String {
+        word.iter().map(|x| x.as_int().to_string()).collect::<Vec<_>>().join(".")
+    }
+
     #[cfg(test)]
     mod tests {
         #[rstest::rstest]
@@ -45,7 +45,10 @@ mod partial;
 pub use partial::PartialNote;
 
 mod recipient;
-pub use recipient::NoteRecipient;
+pub use recipient::{NoteRecipient, NoteRecipientBuilder};
+
+mod schema;
+pub use schema::{NoteSchemaField, NoteSchemaFieldKind, NoteScriptSchema};
 
 mod script;
 pub use script::NoteScript;
@@ -53,6 +56,12 @@ pub use script::NoteScript;
 mod file;
 pub use file::NoteFile;
 
+/// The arguments passed to a note script when the note is consumed.
+///
+/// Note args are provided by the consumer of a note, e.g. via the transaction args, and are
+/// placed on the stack of the note script at the start of its execution.
+pub type NoteArgs = Word;
+
 // NOTE
 // ================================================================================================
 
@@ -1,6 +1,6 @@
 use super::{
-    ByteReader, ByteWriter, Deserializable, DeserializationError, Digest, NoteAssets, NoteHeader,
-    NoteId, NoteMetadata, Serializable,
+    ByteReader, ByteWriter, Deserializable, DeserializationError, Digest, Note, NoteAssets,
+    NoteError, NoteHeader, NoteId, NoteMetadata, NoteRecipient, Serializable,
 };
 
 // PARTIAL NOTE
@@ -47,6 +47,23 @@ impl PartialNote {
     pub fn assets(&self) -> &NoteAssets {
         &self.assets
     }
+
+    /// Completes this partial note with the provided recipient details and returns the resulting
+    /// [Note].
+    ///
+    /// # Errors
+    /// Returns an error if the digest of the provided recipient does not match the recipient
+    /// digest of this note.
+    pub fn complete(self, recipient: NoteRecipient) -> Result<Note, NoteError> {
+        if recipient.digest() != self.recipient_digest {
+            return Err(NoteError::RecipientDigestMismatch {
+                expected: self.recipient_digest,
+                actual: recipient.digest(),
+            });
+        }
+
+        Ok(Note::new(self.assets, self.metadata, recipient))
+    }
 }
 
 impl From<&PartialNote> for NoteHeader {
@@ -81,3 +98,56 @@ impl Deserializable for PartialNote {
         Ok(Self::new(metadata, recipient_digest, assets))
     }
 }
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use assembly::Assembler;
+    use assert_matches::assert_matches;
+    use rand::SeedableRng;
+    use rand_xoshiro::Xoshiro256PlusPlus;
+
+    use super::*;
+    use crate::{
+        account::AccountId,
+        note::{NoteInputs, NoteRecipientBuilder},
+        testing::{account_id::ACCOUNT_ID_OFF_CHAIN_SENDER, note::NoteBuilder},
+        Felt,
+    };
+
+    #[test]
+    fn partial_note_complete() {
+        let sender = AccountId::try_from(ACCOUNT_ID_OFF_CHAIN_SENDER).unwrap();
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(0);
+        let note = NoteBuilder::new(sender, &mut rng)
+            .note_inputs([Felt::new(1), Felt::new(2)])
+            .unwrap()
+            .build(&Assembler::default())
+            .unwrap();
+
+        // the recipient digest can be computed without knowing the assets of the note
+        let recipient = NoteRecipientBuilder::new(note.serial_num(), note.script().clone())
+            .add_input(Felt::new(1))
+            .add_inputs([Felt::new(2)])
+            .build()
+            .unwrap();
+        assert_eq!(&recipient, note.recipient());
+
+        let partial_note = PartialNote::from(&note);
+        assert_eq!(partial_note.clone().complete(recipient).unwrap(), note);
+
+        let other_recipient = NoteRecipient::new(
+            note.serial_num(),
+            note.script().clone(),
+            NoteInputs::new(vec![Felt::new(3)]).unwrap(),
+        );
+        let err = partial_note.complete(other_recipient.clone()).unwrap_err();
+        assert_matches!(
+            err,
+            NoteError::RecipientDigestMismatch { expected, actual }
+                if expected == note.recipient().digest() && actual == other_recipient.digest()
+        );
+    }
+}
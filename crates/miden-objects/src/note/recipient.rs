@@ -4,8 +4,8 @@ use core::fmt::Debug;
 use miden_crypto::Felt;
 
 use super::{
-    ByteReader, ByteWriter, Deserializable, DeserializationError, Digest, Hasher, NoteInputs,
-    NoteScript, Serializable, Word,
+    ByteReader, ByteWriter, Deserializable, DeserializationError, Digest, Hasher, NoteError,
+    NoteInputs, NoteScript, Serializable, Word,
 };
 
 /// Value that describes under which condition a note can be consumed.
@@ -67,6 +67,56 @@ impl NoteRecipient {
     }
 }
 
+// NOTE RECIPIENT BUILDER
+// ================================================================================================
+
+/// A builder for a [NoteRecipient].
+///
+/// The recipient only commits to the note's serial number, script and inputs. This allows the
+/// sender of a note to build the recipient, and thus compute its digest, before the assets of the
+/// note are known. The note can later be completed from a [super::PartialNote] via
+/// [super::PartialNote::complete] or, during transaction execution, by registering the recipient
+/// with the transaction arguments (see
+/// [crate::transaction::TransactionArgs::add_expected_output_recipient]).
+#[derive(Debug, Clone)]
+pub struct NoteRecipientBuilder {
+    serial_num: Word,
+    script: NoteScript,
+    inputs: Vec<Felt>,
+}
+
+impl NoteRecipientBuilder {
+    /// Returns a new [NoteRecipientBuilder] for a recipient with the provided serial number and
+    /// script, and no inputs.
+    pub fn new(serial_num: Word, script: NoteScript) -> Self {
+        Self { serial_num, script, inputs: Vec::new() }
+    }
+
+    /// Appends the provided value to the inputs of the recipient.
+    pub fn add_input(mut self, input: Felt) -> Self {
+        self.inputs.push(input);
+        self
+    }
+
+    /// Appends the provided values to the inputs of the recipient.
+    pub fn add_inputs(mut self, inputs: impl IntoIterator<Item = Felt>) -> Self {
+        self.inputs.extend(inputs);
+        self
+    }
+
+    /// Builds the [NoteRecipient].
+    ///
+    /// # Errors
+    /// Returns an error if the number of inputs exceeds [crate::MAX_INPUTS_PER_NOTE].
+    pub fn build(self) -> Result<NoteRecipient, NoteError> {
+        let inputs = NoteInputs::new(self.inputs)?;
+        Ok(NoteRecipient::new(self.serial_num, self.script, inputs))
+    }
+}
+
+// HELPERS
+// ================================================================================================
+
 fn compute_recipient_digest(serial_num: Word, script: &NoteScript, inputs: &NoteInputs) -> Digest {
     let serial_num_hash = Hasher::merge(&[serial_num.into(), Digest::default()]);
     let merge_script = Hasher::merge(&[serial_num_hash, script.hash()]);
@@ -0,0 +1,354 @@
+use alloc::{format, string::String, vec::Vec};
+
+use super::{NoteArgs, NoteInputs};
+use crate::{
+    account::AccountId, errors::NoteScriptSchemaError, Felt, MAX_INPUTS_PER_NOTE, WORD_SIZE, ZERO,
+};
+
+// NOTE SCHEMA FIELD KIND
+// ================================================================================================
+
+/// Describes the values a note input or note argument element may take.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NoteSchemaFieldKind {
+    /// Any field element.
+    Felt,
+    /// A field element which fits into a `u32`, e.g. a block number or a note tag.
+    U32,
+    /// The suffix of an account ID. It must be directly followed by the prefix of the same
+    /// account ID.
+    AccountIdSuffix,
+    /// The prefix of an account ID. It must directly follow the suffix of the same account ID.
+    AccountIdPrefix,
+}
+
+// NOTE SCHEMA FIELD
+// ================================================================================================
+
+/// Describes the meaning of a single note input or note argument element.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoteSchemaField {
+    name: String,
+    description: String,
+    kind: NoteSchemaFieldKind,
+}
+
+impl NoteSchemaField {
+    /// Returns a new [NoteSchemaField] instantiated from the provided name and description which
+    /// accepts any field element.
+    pub fn new(name: impl Into<String>, description: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            description: description.into(),
+            kind: NoteSchemaFieldKind::Felt,
+        }
+    }
+
+    /// Returns a new [NoteSchemaField] instantiated from the provided name and description which
+    /// only accepts field elements fitting into a `u32`.
+    pub fn u32(name: impl Into<String>, description: impl Into<String>) -> Self {
+        Self {
+            kind: NoteSchemaFieldKind::U32,
+            ..Self::new(name, description)
+        }
+    }
+
+    /// Returns the two fields describing the suffix and the prefix of an account ID, in the order
+    /// in which they are provided to the script.
+    ///
+    /// The fields are named `{name}_suffix` and `{name}_prefix` respectively.
+    pub fn account_id(name: &str, description: &str) -> [Self; 2] {
+        [
+            Self {
+                name: format!("{name}_suffix"),
+                description: format!("suffix of the {description}"),
+                kind: NoteSchemaFieldKind::AccountIdSuffix,
+            },
+            Self {
+                name: format!("{name}_prefix"),
+                description: format!("prefix of the {description}"),
+                kind: NoteSchemaFieldKind::AccountIdPrefix,
+            },
+        ]
+    }
+
+    /// Returns the name of this field.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the description of this field.
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    /// Returns the kind of values accepted by this field.
+    pub fn kind(&self) -> NoteSchemaFieldKind {
+        self.kind
+    }
+}
+
+// NOTE SCRIPT SCHEMA
+// ================================================================================================
+
+/// Describes the note inputs and note args expected by a note script.
+///
+/// A schema declares:
+/// - inputs: the exact number of note inputs the script expects, together with the meaning and the
+///   kind of each input value (see [NoteSchemaFieldKind]).
+/// - args: the meaning of the elements of the note args word which are read by the script. The
+///   elements which are not declared are expected to be [ZERO].
+///
+/// Schemas allow the creator or the consumer of a note to detect a malformed note or malformed note
+/// args before executing the note script. The schemas of the standard note scripts are provided by
+/// the `miden-lib` crate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoteScriptSchema {
+    inputs: Vec<NoteSchemaField>,
+    args: [Option<NoteSchemaField>; WORD_SIZE],
+}
+
+impl NoteScriptSchema {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [NoteScriptSchema] expecting the provided note inputs and no note args.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The number of inputs is greater than [MAX_INPUTS_PER_NOTE].
+    /// - An account ID suffix input is not directly followed by an account ID prefix input, or an
+    ///   account ID prefix input does not directly follow an account ID suffix input.
+    pub fn new(inputs: Vec<NoteSchemaField>) -> Result<Self, NoteScriptSchemaError> {
+        if inputs.len() > MAX_INPUTS_PER_NOTE {
+            return Err(NoteScriptSchemaError::TooManyInputs(inputs.len()));
+        }
+
+        for (index, field) in inputs.iter().enumerate() {
+            let is_paired = match field.kind {
+                NoteSchemaFieldKind::AccountIdSuffix => inputs
+                    .get(index + 1)
+                    .is_some_and(|next| next.kind == NoteSchemaFieldKind::AccountIdPrefix),
+                NoteSchemaFieldKind::AccountIdPrefix => index
+                    .checked_sub(1)
+                    .is_some_and(|prev| inputs[prev].kind == NoteSchemaFieldKind::AccountIdSuffix),
+                NoteSchemaFieldKind::Felt | NoteSchemaFieldKind::U32 => true,
+            };
+            if !is_paired {
+                return Err(NoteScriptSchemaError::UnpairedAccountIdInput(index));
+            }
+        }
+
+        Ok(Self { inputs, args: Default::default() })
+    }
+
+    /// Declares the meaning of the note args element at the specified index and returns the
+    /// updated schema.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The index is not smaller than [WORD_SIZE].
+    /// - An element with the same index was already declared.
+    /// - The field describes a part of an account ID.
+    pub fn with_arg(
+        mut self,
+        index: usize,
+        field: NoteSchemaField,
+    ) -> Result<Self, NoteScriptSchemaError> {
+        if matches!(
+            field.kind,
+            NoteSchemaFieldKind::AccountIdSuffix | NoteSchemaFieldKind::AccountIdPrefix
+        ) {
+            return Err(NoteScriptSchemaError::AccountIdArg(index));
+        }
+
+        let arg = self
+            .args
+            .get_mut(index)
+            .ok_or(NoteScriptSchemaError::ArgIndexOutOfBounds(index))?;
+
+        if arg.is_some() {
+            return Err(NoteScriptSchemaError::DuplicateArg(index));
+        }
+        *arg = Some(field);
+
+        Ok(self)
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the expected note inputs in the order in which they are provided to the script.
+    pub fn inputs(&self) -> &[NoteSchemaField] {
+        &self.inputs
+    }
+
+    /// Returns the declared note args elements, indexed by their position in the note args word.
+    pub fn args(&self) -> &[Option<NoteSchemaField>; WORD_SIZE] {
+        &self.args
+    }
+
+    /// Returns the number of note inputs expected by the script.
+    pub fn num_inputs(&self) -> usize {
+        self.inputs.len()
+    }
+
+    // VALIDATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Checks that the provided note inputs and note args conform to this schema.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The number of note inputs does not match the number of inputs declared by this schema.
+    /// - A note input or a declared note args element of kind [NoteSchemaFieldKind::U32] does not
+    ///   fit into a `u32`.
+    /// - A pair of account ID suffix and prefix inputs does not form a valid [AccountId].
+    /// - A note args element which is not declared by this schema is not [ZERO].
+    pub fn check(&self, inputs: &NoteInputs, args: &NoteArgs) -> Result<(), NoteScriptSchemaError> {
+        let values = inputs.values();
+        if values.len() != self.inputs.len() {
+            return Err(NoteScriptSchemaError::InputsCountMismatch {
+                expected: self.inputs.len(),
+                actual: values.len(),
+            });
+        }
+
+        for (index, (value, field)) in values.iter().zip(self.inputs.iter()).enumerate() {
+            match field.kind {
+                NoteSchemaFieldKind::U32 if !fits_into_u32(*value) => {
+                    return Err(NoteScriptSchemaError::InputNotU32 { index, value: *value });
+                },
+                NoteSchemaFieldKind::AccountIdSuffix => {
+                    // the schema guarantees that a suffix input is followed by a prefix input
+                    AccountId::try_from([values[index + 1], *value]).map_err(|source| {
+                        NoteScriptSchemaError::InvalidAccountIdInputs { index, source }
+                    })?;
+                },
+                _ => (),
+            }
+        }
+
+        for (index, (value, field)) in args.iter().zip(self.args.iter()).enumerate() {
+            match field {
+                None if *value != ZERO => {
+                    return Err(NoteScriptSchemaError::UndeclaredArg { index, value: *value });
+                },
+                Some(field) if field.kind == NoteSchemaFieldKind::U32 && !fits_into_u32(*value) => {
+                    return Err(NoteScriptSchemaError::ArgNotU32 { index, value: *value });
+                },
+                _ => (),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns `true` if the provided value fits into a `u32`.
+fn fits_into_u32(value: Felt) -> bool {
+    value.as_int() <= u32::MAX as u64
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+
+    use super::*;
+    use crate::{
+        testing::account_id::ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN, AccountIdError,
+        EMPTY_WORD,
+    };
+
+    fn mock_schema() -> NoteScriptSchema {
+        let mut inputs =
+            NoteSchemaField::account_id("target_id", "ID of the target account").to_vec();
+        inputs.push(NoteSchemaField::u32("height", "a block height"));
+
+        NoteScriptSchema::new(inputs)
+            .unwrap()
+            .with_arg(3, NoteSchemaField::u32("action", "the action to perform"))
+            .unwrap()
+    }
+
+    fn mock_inputs(height: Felt) -> NoteInputs {
+        let target =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+        NoteInputs::new(vec![target.suffix(), target.prefix().as_felt(), height]).unwrap()
+    }
+
+    #[test]
+    fn note_script_schema_check() {
+        let schema = mock_schema();
+        assert_eq!(schema.inputs()[0].name(), "target_id_suffix");
+        assert_eq!(schema.inputs()[1].description(), "prefix of the ID of the target account");
+
+        let inputs = mock_inputs(Felt::new(1));
+        schema.check(&inputs, &EMPTY_WORD).unwrap();
+        schema.check(&inputs, &[ZERO, ZERO, ZERO, Felt::new(5)]).unwrap();
+
+        let err = schema
+            .check(&NoteInputs::new(vec![Felt::new(1)]).unwrap(), &EMPTY_WORD)
+            .unwrap_err();
+        assert_matches!(err, NoteScriptSchemaError::InputsCountMismatch { expected: 3, actual: 1 });
+
+        let err = schema.check(&inputs, &[ZERO, Felt::new(7), ZERO, ZERO]).unwrap_err();
+        assert_matches!(err, NoteScriptSchemaError::UndeclaredArg { index: 1, value } if value == Felt::new(7));
+    }
+
+    #[test]
+    fn note_script_schema_check_field_kinds() {
+        let schema = mock_schema();
+        let too_large = Felt::new(u32::MAX as u64 + 1);
+
+        let err = schema.check(&mock_inputs(too_large), &EMPTY_WORD).unwrap_err();
+        assert_matches!(err, NoteScriptSchemaError::InputNotU32 { index: 2, value } if value == too_large);
+
+        let err = schema.check(&mock_inputs(ZERO), &[ZERO, ZERO, ZERO, too_large]).unwrap_err();
+        assert_matches!(err, NoteScriptSchemaError::ArgNotU32 { index: 3, value } if value == too_large);
+
+        // the suffix of an account ID must have its lower 8 bits set to zero
+        let target =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+        let inputs = NoteInputs::new(vec![Felt::new(1), target.prefix().as_felt(), ZERO]).unwrap();
+        let err = schema.check(&inputs, &EMPTY_WORD).unwrap_err();
+        assert_matches!(
+            err,
+            NoteScriptSchemaError::InvalidAccountIdInputs {
+                index: 0,
+                source: AccountIdError::AccountIdSuffixLeastSignificantByteMustBeZero
+            }
+        );
+    }
+
+    #[test]
+    fn note_script_schema_invalid_fields() {
+        let [suffix, prefix] = NoteSchemaField::account_id("target_id", "ID of the target account");
+
+        let err = NoteScriptSchema::new(vec![suffix.clone()]).unwrap_err();
+        assert_matches!(err, NoteScriptSchemaError::UnpairedAccountIdInput(0));
+
+        let err = NoteScriptSchema::new(vec![prefix.clone(), suffix.clone()]).unwrap_err();
+        assert_matches!(err, NoteScriptSchemaError::UnpairedAccountIdInput(0));
+
+        let err = mock_schema().with_arg(0, prefix).unwrap_err();
+        assert_matches!(err, NoteScriptSchemaError::AccountIdArg(0));
+    }
+
+    #[test]
+    fn note_script_schema_invalid_args() {
+        let field = NoteSchemaField::new("arg", "an argument");
+
+        let err = mock_schema().with_arg(4, field.clone()).unwrap_err();
+        assert_matches!(err, NoteScriptSchemaError::ArgIndexOutOfBounds(4));
+
+        let err = mock_schema().with_arg(3, field).unwrap_err();
+        assert_matches!(err, NoteScriptSchemaError::DuplicateArg(3));
+    }
+}
@@ -1,10 +1,3 @@
-use alloc::{
-    string::{String, ToString},
-    vec::Vec,
-};
-
-use vm_core::Word;
-
 pub mod account;
 pub mod account_code;
 pub mod account_component;
@@ -15,7 +8,4 @@ pub mod constants;
 pub mod note;
 pub mod storage;
 
-/// Converts a word to MASM
-pub fn prepare_word(word: &Word) -> String {
-    word.iter().map(|x| x.as_int().to_string()).collect::<Vec<_>>().join(".")
-}
+pub use crate::utils::prepare_word;
@@ -7,7 +7,7 @@ use vm_processor::DeserializationError;
 use super::{
     Account, AccountDelta, AccountHeader, AccountId, AdviceInputs, BlockHeader, InputNote,
     InputNotes, NoteId, OutputNotes, TransactionArgs, TransactionId, TransactionInputs,
-    TransactionOutputs, TransactionWitness,
+    TransactionLogs, TransactionOutputs, TransactionWitness,
 };
 use crate::account::AccountCode;
 
@@ -31,6 +31,7 @@ pub struct ExecutedTransaction {
     tx_outputs: TransactionOutputs,
     account_codes: Vec<AccountCode>,
     account_delta: AccountDelta,
+    logs: TransactionLogs,
     tx_args: TransactionArgs,
     advice_witness: AdviceInputs,
     tx_measurements: TransactionMeasurements,
@@ -44,11 +45,13 @@ impl ExecutedTransaction {
     ///
     /// # Panics
     /// Panics if input and output account IDs are not the same.
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         tx_inputs: TransactionInputs,
         tx_outputs: TransactionOutputs,
         account_codes: Vec<AccountCode>,
         account_delta: AccountDelta,
+        logs: TransactionLogs,
         tx_args: TransactionArgs,
         advice_witness: AdviceInputs,
         tx_measurements: TransactionMeasurements,
@@ -62,6 +65,7 @@ impl ExecutedTransaction {
             tx_outputs,
             account_codes,
             account_delta,
+            logs,
             tx_args,
             advice_witness,
             tx_measurements,
@@ -116,6 +120,11 @@ impl ExecutedTransaction {
         &self.account_delta
     }
 
+    /// Returns the application log entries emitted during the execution of this transaction.
+    pub fn logs(&self) -> &TransactionLogs {
+        &self.logs
+    }
+
     /// Returns a reference to the inputs for this transaction.
     pub fn tx_inputs(&self) -> &TransactionInputs {
         &self.tx_inputs
@@ -170,6 +179,7 @@ impl Serializable for ExecutedTransaction {
         self.tx_outputs.write_into(target);
         self.account_codes.write_into(target);
         self.account_delta.write_into(target);
+        self.logs.write_into(target);
         self.tx_args.write_into(target);
         self.advice_witness.write_into(target);
         self.tx_measurements.write_into(target);
@@ -182,6 +192,7 @@ impl Deserializable for ExecutedTransaction {
         let tx_outputs = TransactionOutputs::read_from(source)?;
         let account_codes = Vec::<AccountCode>::read_from(source)?;
         let account_delta = AccountDelta::read_from(source)?;
+        let logs = TransactionLogs::read_from(source)?;
         let tx_args = TransactionArgs::read_from(source)?;
         let advice_witness = AdviceInputs::read_from(source)?;
         let tx_measurements = TransactionMeasurements::read_from(source)?;
@@ -191,6 +202,7 @@ impl Deserializable for ExecutedTransaction {
             tx_outputs,
             account_codes,
             account_delta,
+            logs,
             tx_args,
             advice_witness,
             tx_measurements,
@@ -0,0 +1,137 @@
+use alloc::vec::Vec;
+
+use super::{Digest, Hasher, Word};
+use crate::utils::serde::{
+    ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable,
+};
+
+// LOG ENTRY
+// ================================================================================================
+
+/// An application log entry emitted during transaction execution via `miden::tx::emit_log`.
+///
+/// A log entry consists of:
+/// - emitter: the MAST root of the account procedure, note script or transaction script which
+///   emitted the entry.
+/// - topic: a word by which the entry can be indexed.
+/// - data: the payload of the entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LogEntry {
+    emitter: Digest,
+    topic: Word,
+    data: Word,
+}
+
+impl LogEntry {
+    /// Returns a new [LogEntry] instantiated from the provided emitter, topic and data.
+    pub fn new(emitter: Digest, topic: Word, data: Word) -> Self {
+        Self { emitter, topic, data }
+    }
+
+    /// Returns the MAST root of the procedure or script which emitted this entry.
+    pub fn emitter(&self) -> Digest {
+        self.emitter
+    }
+
+    /// Returns the topic of this entry.
+    pub fn topic(&self) -> Word {
+        self.topic
+    }
+
+    /// Returns the data of this entry.
+    pub fn data(&self) -> Word {
+        self.data
+    }
+
+    /// Returns a commitment to this entry.
+    ///
+    /// The commitment is computed as `hash(emitter, hash(topic, data))`.
+    pub fn commitment(&self) -> Digest {
+        Hasher::merge(&[self.emitter, Hasher::merge(&[self.topic.into(), self.data.into()])])
+    }
+}
+
+impl Serializable for LogEntry {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.emitter.write_into(target);
+        self.topic.write_into(target);
+        self.data.write_into(target);
+    }
+}
+
+impl Deserializable for LogEntry {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let emitter = Digest::read_from(source)?;
+        let topic = Word::read_from(source)?;
+        let data = Word::read_from(source)?;
+
+        Ok(Self::new(emitter, topic, data))
+    }
+}
+
+// TRANSACTION LOGS
+// ================================================================================================
+
+/// The list of [LogEntry]s emitted during the execution of a transaction, in emission order.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TransactionLogs(Vec<LogEntry>);
+
+impl TransactionLogs {
+    /// Returns new [TransactionLogs] instantiated from the provided entries.
+    pub fn new(entries: Vec<LogEntry>) -> Self {
+        Self(entries)
+    }
+
+    /// Returns the number of log entries.
+    pub fn num_entries(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns true if no log entries were emitted.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns an iterator over the log entries.
+    pub fn iter(&self) -> impl Iterator<Item = &LogEntry> {
+        self.0.iter()
+    }
+
+    /// Returns an iterator over the log entries with the specified topic.
+    pub fn with_topic(&self, topic: Word) -> impl Iterator<Item = &LogEntry> {
+        self.0.iter().filter(move |entry| entry.topic == topic)
+    }
+
+    /// Returns the commitment to the log entries, or `None` if no entries were emitted.
+    ///
+    /// The commitment is computed sequentially as `commitment = hash(commitment, entry_commitment)`
+    /// over all entries, starting from an empty word. This matches the commitment output by the
+    /// transaction kernel.
+    pub fn commitment(&self) -> Option<Digest> {
+        if self.is_empty() {
+            return None;
+        }
+
+        Some(self.0.iter().fold(Digest::default(), |commitment, entry| {
+            Hasher::merge(&[commitment, entry.commitment()])
+        }))
+    }
+}
+
+impl From<Vec<LogEntry>> for TransactionLogs {
+    fn from(entries: Vec<LogEntry>) -> Self {
+        Self::new(entries)
+    }
+}
+
+impl Serializable for TransactionLogs {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.0.write_into(target);
+    }
+}
+
+impl Deserializable for TransactionLogs {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(Self(Vec::<LogEntry>::read_from(source)?))
+    }
+}
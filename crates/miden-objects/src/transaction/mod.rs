@@ -9,6 +9,7 @@ use super::{
 mod chain_mmr;
 mod executed_tx;
 mod inputs;
+mod logs;
 mod outputs;
 mod proven_tx;
 mod transaction_id;
@@ -18,6 +19,7 @@ mod tx_witness;
 pub use chain_mmr::ChainMmr;
 pub use executed_tx::{ExecutedTransaction, TransactionMeasurements};
 pub use inputs::{InputNote, InputNotes, ToInputNoteCommitments, TransactionInputs};
+pub use logs::{LogEntry, TransactionLogs};
 pub use outputs::{OutputNote, OutputNotes, TransactionOutputs};
 pub use proven_tx::{
     InputNoteCommitment, ProvenTransaction, ProvenTransactionBuilder, TxAccountUpdate,
@@ -22,6 +22,9 @@ pub struct TransactionOutputs {
     pub output_notes: OutputNotes,
     /// Defines up to which block the transaction is considered valid.
     pub expiration_block_num: BlockNumber,
+    /// Commitment to the log entries emitted by the transaction, or `None` if no log entries were
+    /// emitted.
+    pub logs_commitment: Option<Digest>,
 }
 
 impl Serializable for TransactionOutputs {
@@ -29,6 +32,7 @@ impl Serializable for TransactionOutputs {
         self.account.write_into(target);
         self.output_notes.write_into(target);
         self.expiration_block_num.write_into(target);
+        self.logs_commitment.write_into(target);
     }
 }
 
@@ -37,11 +41,13 @@ impl Deserializable for TransactionOutputs {
         let account = AccountHeader::read_from(source)?;
         let output_notes = OutputNotes::read_from(source)?;
         let expiration_block_num = BlockNumber::read_from(source)?;
+        let logs_commitment = <Option<Digest>>::read_from(source)?;
 
         Ok(Self {
             account,
             output_notes,
             expiration_block_num,
+            logs_commitment,
         })
     }
 }
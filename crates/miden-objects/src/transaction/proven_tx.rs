@@ -4,7 +4,7 @@ use miden_verifier::ExecutionProof;
 
 use super::{InputNote, ToInputNoteCommitments};
 use crate::{
-    account::delta::AccountUpdateDetails,
+    account::{delta::AccountUpdateDetails, AccountType},
     block::BlockNumber,
     note::NoteHeader,
     transaction::{
@@ -40,6 +40,10 @@ pub struct ProvenTransaction {
     /// The block number by which the transaction will expire, as defined by the executed scripts.
     expiration_block_num: BlockNumber,
 
+    /// Commitment to the log entries emitted by the transaction, or `None` if no log entries were
+    /// emitted.
+    logs_commitment: Option<Digest>,
+
     /// A STARK proof that attests to the correct execution of the transaction.
     proof: ExecutionProof,
 }
@@ -90,6 +94,12 @@ impl ProvenTransaction {
         self.expiration_block_num
     }
 
+    /// Returns the commitment to the log entries emitted by the transaction, or `None` if no log
+    /// entries were emitted.
+    pub fn logs_commitment(&self) -> Option<Digest> {
+        self.logs_commitment
+    }
+
     /// Returns an iterator over the nullifiers of all input notes in this transaction.
     ///
     /// This includes both authenticated and unauthenticated notes.
@@ -139,6 +149,26 @@ impl ProvenTransaction {
                         ));
                     }
                 },
+                AccountUpdateDetails::CodeUpgrade(_, ref delta) => {
+                    if is_new_account {
+                        return Err(ProvenTransactionError::NewOnChainAccountRequiresFullDetails(
+                            self.account_id(),
+                        ));
+                    }
+                    if self.account_id().account_type() != AccountType::RegularAccountUpdatableCode
+                    {
+                        return Err(ProvenTransactionError::AccountCodeNotUpdatable(
+                            self.account_id(),
+                        ));
+                    }
+                    if delta.nonce().is_none() {
+                        return Err(
+                            ProvenTransactionError::AccountCodeUpgradeWithoutNonceIncrement(
+                                self.account_id(),
+                            ),
+                        );
+                    }
+                },
             }
         } else if !self.account_update.is_private() {
             return Err(ProvenTransactionError::OffChainAccountWithDetails(self.account_id()));
@@ -155,6 +185,7 @@ impl Serializable for ProvenTransaction {
         self.output_notes.write_into(target);
         self.block_ref.write_into(target);
         self.expiration_block_num.write_into(target);
+        self.logs_commitment.write_into(target);
         self.proof.write_into(target);
     }
 }
@@ -168,6 +199,7 @@ impl Deserializable for ProvenTransaction {
 
         let block_ref = Digest::read_from(source)?;
         let expiration_block_num = BlockNumber::read_from(source)?;
+        let logs_commitment = <Option<Digest>>::read_from(source)?;
         let proof = ExecutionProof::read_from(source)?;
 
         let id = TransactionId::new(
@@ -184,6 +216,7 @@ impl Deserializable for ProvenTransaction {
             output_notes,
             block_ref,
             expiration_block_num,
+            logs_commitment,
             proof,
         };
 
@@ -223,6 +256,9 @@ pub struct ProvenTransactionBuilder {
     /// The block number by which the transaction will expire, as defined by the executed scripts.
     expiration_block_num: BlockNumber,
 
+    /// Commitment to the log entries emitted by the transaction, if any.
+    logs_commitment: Option<Digest>,
+
     /// A STARK proof that attests to the correct execution of the transaction.
     proof: ExecutionProof,
 }
@@ -249,6 +285,7 @@ impl ProvenTransactionBuilder {
             output_notes: Vec::new(),
             block_ref,
             expiration_block_num,
+            logs_commitment: None,
             proof,
         }
     }
@@ -262,6 +299,12 @@ impl ProvenTransactionBuilder {
         self
     }
 
+    /// Sets the commitment to the log entries emitted by the transaction.
+    pub fn logs_commitment(mut self, commitment: Digest) -> Self {
+        self.logs_commitment = Some(commitment);
+        self
+    }
+
     /// Add notes consumed by the transaction.
     pub fn add_input_notes<I, T>(mut self, notes: I) -> Self
     where
@@ -312,6 +355,7 @@ impl ProvenTransactionBuilder {
             output_notes,
             block_ref: self.block_ref,
             expiration_block_num: self.expiration_block_num,
+            logs_commitment: self.logs_commitment,
             proof: self.proof,
         };
 
@@ -12,7 +12,8 @@ use vm_processor::{AdviceInputs, DeserializationError};
 
 use super::{Digest, Felt, Word};
 use crate::{
-    note::{NoteDetails, NoteId},
+    account::AccountCode,
+    note::{NoteDetails, NoteId, NoteRecipient},
     TransactionScriptError,
 };
 
@@ -27,11 +28,14 @@ use crate::{
 ///   different from note inputs, as the user executing the transaction can specify arbitrary note
 ///   args.
 /// - Advice inputs: Provides data needed by the runtime, like the details of public output notes.
+/// - New account code: the code the account is upgraded to, if the transaction upgrades the code of
+///   the account via `miden::account::set_code`.
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub struct TransactionArgs {
     tx_script: Option<TransactionScript>,
     note_args: BTreeMap<NoteId, Word>,
     advice_inputs: AdviceInputs,
+    new_account_code: Option<AccountCode>,
 }
 
 impl TransactionArgs {
@@ -59,6 +63,7 @@ impl TransactionArgs {
             tx_script,
             note_args: note_args.unwrap_or_default(),
             advice_inputs,
+            new_account_code: None,
         }
     }
 
@@ -79,6 +84,16 @@ impl TransactionArgs {
         self
     }
 
+    /// Returns the provided [TransactionArgs] with the code the account is upgraded to by the
+    /// transaction.
+    ///
+    /// The code must be provided if the transaction sets a new code commitment for the account, so
+    /// that the new code can be included in the account update of the transaction.
+    pub fn with_new_account_code(mut self, code: AccountCode) -> Self {
+        self.new_account_code = Some(code);
+        self
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -97,6 +112,11 @@ impl TransactionArgs {
         &self.advice_inputs
     }
 
+    /// Returns a reference to the code the account is upgraded to by the transaction, if any.
+    pub fn new_account_code(&self) -> Option<&AccountCode> {
+        self.new_account_code.as_ref()
+    }
+
     // STATE MUTATORS
     // --------------------------------------------------------------------------------------------
 
@@ -109,9 +129,21 @@ impl TransactionArgs {
     ///   adding ONE to its most significant element.
     /// - script_hash |-> script.
     pub fn add_expected_output_note<T: Deref<Target = NoteDetails>>(&mut self, note: &T) {
-        let recipient = note.recipient();
-        let inputs = note.inputs();
-        let script = note.script();
+        self.add_expected_output_recipient(note.recipient());
+    }
+
+    /// Populates the advice inputs with the specified recipient details.
+    ///
+    /// This allows the recipient of an output note to be provided before the assets of the note
+    /// are known. When a note with this recipient is created by the transaction, the full note is
+    /// built from the recipient and the assets moved into the note, i.e., the note is output as
+    /// [super::OutputNote::Full] rather than [super::OutputNote::Partial].
+    ///
+    /// The advice inputs' map is extended with the same keys as in
+    /// [TransactionArgs::add_expected_output_note].
+    pub fn add_expected_output_recipient(&mut self, recipient: &NoteRecipient) {
+        let inputs = recipient.inputs();
+        let script = recipient.script();
         let script_encoded: Vec<Felt> = script.into();
 
         let new_elements = [
@@ -157,6 +189,7 @@ impl Serializable for TransactionArgs {
         self.tx_script.write_into(target);
         self.note_args.write_into(target);
         self.advice_inputs.write_into(target);
+        self.new_account_code.write_into(target);
     }
 }
 
@@ -165,8 +198,14 @@ impl Deserializable for TransactionArgs {
         let tx_script = Option::<TransactionScript>::read_from(source)?;
         let note_args = BTreeMap::<NoteId, Word>::read_from(source)?;
         let advice_inputs = AdviceInputs::read_from(source)?;
+        let new_account_code = Option::<AccountCode>::read_from(source)?;
 
-        Ok(Self { tx_script, note_args, advice_inputs })
+        Ok(Self {
+            tx_script,
+            note_args,
+            advice_inputs,
+            new_account_code,
+        })
     }
 }
 
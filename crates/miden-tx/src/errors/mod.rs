@@ -2,7 +2,7 @@ use alloc::{boxed::Box, string::String};
 use core::error::Error;
 
 use miden_objects::{
-    account::AccountId, block::BlockNumber, note::NoteId, AccountError, Felt,
+    account::AccountId, block::BlockNumber, note::NoteId, AccountError, Digest, Felt,
     ProvenTransactionError, TransactionInputError, TransactionOutputError,
 };
 use miden_verifier::VerificationError;
@@ -28,6 +28,13 @@ pub enum TransactionExecutorError {
         expected: Option<Felt>,
         actual: Option<Felt>,
     },
+    #[error("expected logs commitment {expected:?}, found {actual:?}")]
+    InconsistentLogsCommitment {
+        expected: Option<Digest>,
+        actual: Option<Digest>,
+    },
+    #[error("account code with commitment {0} which the account was upgraded to was not provided in the transaction arguments")]
+    MissingNewAccountCode(Digest),
     #[error("failed to construct transaction outputs")]
     TransactionOutputConstructionFailed(#[source] TransactionOutputError),
     #[error("failed to create transaction host")]
@@ -41,6 +48,10 @@ pub enum TransactionExecutorError {
 pub enum TransactionProverError {
     #[error("failed to apply account delta")]
     AccountDeltaApplyFailed(#[source] AccountError),
+    #[error("failed to upgrade account code")]
+    AccountCodeUpgradeFailed(#[source] AccountError),
+    #[error("account code with commitment {0} which the account was upgraded to was not provided in the transaction arguments")]
+    MissingNewAccountCode(Digest),
     #[error("failed to construct transaction outputs")]
     TransactionOutputConstructionFailed(#[source] TransactionOutputError),
     #[error("failed to build proven transaction")]
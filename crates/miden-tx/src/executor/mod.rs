@@ -1,12 +1,15 @@
 use alloc::{collections::BTreeSet, sync::Arc, vec::Vec};
 
-use miden_lib::transaction::TransactionKernel;
+use miden_lib::{
+    transaction::{ExecutionContextCache, TransactionKernel},
+    utils::sync::RwLock,
+};
 use miden_objects::{
     account::{AccountCode, AccountId},
     assembly::Library,
     block::BlockNumber,
     note::NoteId,
-    transaction::{ExecutedTransaction, TransactionArgs, TransactionInputs},
+    transaction::{ExecutedTransaction, TransactionArgs, TransactionInputs, TransactionLogs},
     vm::StackOutputs,
     MAX_TX_EXECUTION_CYCLES, MIN_TX_EXECUTION_CYCLES, ZERO,
 };
@@ -42,6 +45,9 @@ pub struct TransactionExecutor {
     /// [Self::load_account_code()] method.
     account_codes: BTreeSet<AccountCode>,
     exec_options: ExecutionOptions,
+    /// If set, caches the advice inputs shared by sequential transactions executed against the
+    /// same account and reference block, see [Self::with_execution_cache()].
+    execution_cache: Option<RwLock<ExecutionContextCache>>,
 }
 
 impl TransactionExecutor {
@@ -68,6 +74,7 @@ impl TransactionExecutor {
             )
             .expect("Must not fail while max cycles is more than min trace length"),
             account_codes: BTreeSet::new(),
+            execution_cache: None,
         }
     }
 
@@ -91,6 +98,17 @@ impl TransactionExecutor {
         self
     }
 
+    /// Enables caching of transaction inputs for the created instance of [TransactionExecutor].
+    ///
+    /// When caching is enabled, the kernel, chain MMR and unchanged account data prepared for a
+    /// transaction is reused by the next transaction executed against the same account and
+    /// reference block, which reduces the time needed to prepare the inputs of sequential
+    /// transactions against the same account. See [ExecutionContextCache] for details.
+    pub fn with_execution_cache(mut self) -> Self {
+        self.execution_cache = Some(RwLock::new(ExecutionContextCache::new()));
+        self
+    }
+
     // STATE MUTATORS
     // --------------------------------------------------------------------------------------------
 
@@ -137,8 +155,15 @@ impl TransactionExecutor {
             maybe_await!(self.data_store.get_transaction_inputs(account_id, block_ref, notes))
                 .map_err(TransactionExecutorError::FetchTransactionInputsFailed)?;
 
-        let (stack_inputs, advice_inputs) =
-            TransactionKernel::prepare_inputs(&tx_inputs, &tx_args, None);
+        let (stack_inputs, advice_inputs) = match &self.execution_cache {
+            Some(cache) => TransactionKernel::prepare_inputs_with_cache(
+                &tx_inputs,
+                &tx_args,
+                None,
+                &mut cache.write(),
+            ),
+            None => TransactionKernel::prepare_inputs(&tx_inputs, &tx_args, None),
+        };
         let advice_recorder: RecAdviceProvider = advice_inputs.into();
 
         // load note script MAST into the MAST store
@@ -195,7 +220,7 @@ fn build_executed_transaction(
     host: TransactionHost<RecAdviceProvider>,
     account_codes: Vec<AccountCode>,
 ) -> Result<ExecutedTransaction, TransactionExecutorError> {
-    let (advice_recorder, account_delta, output_notes, generated_signatures, tx_progress) =
+    let (advice_recorder, account_delta, output_notes, logs, generated_signatures, tx_progress) =
         host.into_parts();
 
     let (mut advice_witness, _, map, _store) = advice_recorder.finalize();
@@ -231,6 +256,23 @@ fn build_executed_transaction(
         });
     }
 
+    // make sure the code the account was upgraded to, if any, was provided
+    let final_code_commitment = final_account.code_commitment();
+    if final_code_commitment != initial_account.code().commitment()
+        && tx_args.new_account_code().map(AccountCode::commitment) != Some(final_code_commitment)
+    {
+        return Err(TransactionExecutorError::MissingNewAccountCode(final_code_commitment));
+    }
+
+    // make sure the log entries recorded by the host match the commitment output by the kernel
+    let logs = TransactionLogs::new(logs);
+    if logs.commitment() != tx_outputs.logs_commitment {
+        return Err(TransactionExecutorError::InconsistentLogsCommitment {
+            expected: tx_outputs.logs_commitment,
+            actual: logs.commitment(),
+        });
+    }
+
     // introduce generated signatures into the witness inputs
     advice_witness.extend_map(generated_signatures);
 
@@ -239,6 +281,7 @@ fn build_executed_transaction(
         tx_outputs,
         account_codes,
         account_delta,
+        logs,
         tx_args,
         advice_witness,
         tx_progress.into(),
@@ -17,7 +17,7 @@ use miden_objects::{
     account::{AccountDelta, AccountHeader},
     asset::Asset,
     note::NoteId,
-    transaction::{OutputNote, TransactionMeasurements},
+    transaction::{LogEntry, OutputNote, TransactionMeasurements},
     vm::{RowIndex, SystemEvent},
     Digest, Hasher,
 };
@@ -71,6 +71,11 @@ pub struct TransactionHost<A> {
     /// map.
     output_notes: BTreeMap<usize, OutputNoteBuilder>,
 
+    /// The application log entries emitted while executing a transaction, in emission order.
+    ///
+    /// This field is updated by the [TransactionHost::on_event()] handler.
+    logs: Vec<LogEntry>,
+
     /// Serves signature generation requests from the transaction runtime for signatures which are
     /// not present in the `generated_signatures` field.
     authenticator: Option<Arc<dyn TransactionAuthenticator>>,
@@ -116,6 +121,7 @@ impl<A: AdviceProvider> TransactionHost<A> {
             account_delta: AccountDeltaTracker::new(&account),
             acct_procedure_index_map: proc_index_map,
             output_notes: BTreeMap::default(),
+            logs: Vec::new(),
             authenticator,
             tx_progress: TransactionProgress::default(),
             generated_signatures: BTreeMap::new(),
@@ -123,14 +129,16 @@ impl<A: AdviceProvider> TransactionHost<A> {
         })
     }
 
-    /// Consumes `self` and returns the advice provider, account delta, output notes, generated
-    /// signatures, and transaction progress.
+    /// Consumes `self` and returns the advice provider, account delta, output notes, log entries,
+    /// generated signatures, and transaction progress.
+    #[allow(clippy::type_complexity)]
     pub fn into_parts(
         self,
     ) -> (
         A,
         AccountDelta,
         Vec<OutputNote>,
+        Vec<LogEntry>,
         BTreeMap<Digest, Vec<Felt>>,
         TransactionProgress,
     ) {
@@ -140,6 +148,7 @@ impl<A: AdviceProvider> TransactionHost<A> {
             self.adv_provider,
             self.account_delta.into_delta(),
             output_notes,
+            self.logs,
             self.generated_signatures,
             self.tx_progress,
         )
@@ -175,6 +184,19 @@ impl<A: AdviceProvider> TransactionHost<A> {
         Ok(())
     }
 
+    /// Records the application log entry described by the data on the operand stack.
+    ///
+    /// Expected stack state: `[EMITTER, TOPIC, DATA, ...]`
+    fn on_log_emitted(&mut self, process: ProcessState) -> Result<(), TransactionKernelError> {
+        let emitter = process.get_stack_word(0).into();
+        let topic = process.get_stack_word(1);
+        let data = process.get_stack_word(2);
+
+        self.logs.push(LogEntry::new(emitter, topic, data));
+
+        Ok(())
+    }
+
     /// Adds an asset at the top of the [OutputNoteBuilder] identified by the note pointer.
     ///
     /// Expected stack state: [ASSET, note_ptr, num_of_assets, note_idx]
@@ -541,6 +563,8 @@ impl<A: AdviceProvider> Host for TransactionHost<A> {
 
             TransactionEvent::NoteBeforeAddAsset => self.on_note_before_add_asset(process),
             TransactionEvent::NoteAfterAddAsset => Ok(()),
+
+            TransactionEvent::LogEmitted => self.on_log_emitted(process),
         }
         .map_err(|err| ExecutionError::EventError(Box::new(err)))?;
 
@@ -123,7 +123,7 @@ impl TransactionProver for LocalTransactionProver {
         .map_err(TransactionProverError::TransactionProgramExecutionFailed)?;
 
         // extract transaction outputs and process transaction data
-        let (advice_provider, account_delta, output_notes, _signatures, _tx_progress) =
+        let (advice_provider, account_delta, output_notes, _logs, _signatures, _tx_progress) =
             host.into_parts();
         let (_, map, _) = advice_provider.into_parts();
         let tx_outputs =
@@ -144,6 +144,23 @@ impl TransactionProver for LocalTransactionProver {
         .add_input_notes(input_notes)
         .add_output_notes(output_notes);
 
+        let builder = match tx_outputs.logs_commitment {
+            Some(logs_commitment) => builder.logs_commitment(logs_commitment),
+            None => builder,
+        };
+
+        // get the code the account was upgraded to, if the transaction upgraded the account code
+        let final_code_commitment = tx_outputs.account.code_commitment();
+        let new_account_code = if final_code_commitment != account.code().commitment() {
+            let code = tx_args
+                .new_account_code()
+                .filter(|code| code.commitment() == final_code_commitment)
+                .ok_or(TransactionProverError::MissingNewAccountCode(final_code_commitment))?;
+            Some(code.clone())
+        } else {
+            None
+        };
+
         let builder = match account.is_public() {
             true => {
                 let account_update_details = if account.is_new() {
@@ -151,10 +168,18 @@ impl TransactionProver for LocalTransactionProver {
                     account
                         .apply_delta(&account_delta)
                         .map_err(TransactionProverError::AccountDeltaApplyFailed)?;
+                    if let Some(code) = new_account_code {
+                        account
+                            .upgrade_code(code)
+                            .map_err(TransactionProverError::AccountCodeUpgradeFailed)?;
+                    }
 
                     AccountUpdateDetails::New(account)
                 } else {
-                    AccountUpdateDetails::Delta(account_delta)
+                    match new_account_code {
+                        Some(code) => AccountUpdateDetails::CodeUpgrade(code, account_delta),
+                        None => AccountUpdateDetails::Delta(account_delta),
+                    }
                 };
 
                 builder.account_update_details(account_update_details)
@@ -1,7 +1,11 @@
 use alloc::{collections::BTreeMap, vec::Vec};
 
 use miden_lib::{
-    account::{auth::RpoFalcon512, faucets::BasicFungibleFaucet, wallets::BasicWallet},
+    account::{
+        auth::{RpoFalcon512, RpoFalcon512Upgradable},
+        faucets::BasicFungibleFaucet,
+        wallets::BasicWallet,
+    },
     note::{create_p2id_note, create_p2idr_note},
     transaction::{memory, TransactionKernel},
 };
@@ -23,7 +27,7 @@ use miden_objects::{
     testing::account_code::DEFAULT_AUTH_SCRIPT,
     transaction::{
         ChainMmr, ExecutedTransaction, InputNote, InputNotes, OutputNote, ToInputNoteCommitments,
-        TransactionId, TransactionInputs, TransactionScript,
+        TransactionInputs, TransactionScript,
     },
     AccountError, NoteError, ACCOUNT_TREE_DEPTH,
 };
@@ -54,6 +58,10 @@ pub enum Auth {
     /// authenticating the account.
     BasicAuth,
 
+    /// Same as [Auth::BasicAuth], but uses the [RpoFalcon512Upgradable] component which
+    /// additionally allows the owner to upgrade the account code.
+    UpgradableAuth,
+
     /// Does not create any authentication mechanism for the account.
     NoAuth,
 }
@@ -63,12 +71,15 @@ impl Auth {
     /// [`BasicAuthenticator`] or `None` when [`Auth::NoAuth`] is passed.
     fn build_component(&self) -> Option<(AccountComponent, BasicAuthenticator<ChaCha20Rng>)> {
         match self {
-            Auth::BasicAuth => {
+            Auth::BasicAuth | Auth::UpgradableAuth => {
                 let mut rng = ChaCha20Rng::from_seed(Default::default());
                 let sec_key = SecretKey::with_rng(&mut rng);
                 let pub_key = sec_key.public_key();
 
-                let component = RpoFalcon512::new(pub_key).into();
+                let component = match self {
+                    Auth::UpgradableAuth => RpoFalcon512Upgradable::new(pub_key).into(),
+                    _ => RpoFalcon512::new(pub_key).into(),
+                };
 
                 let authenticator = BasicAuthenticator::<ChaCha20Rng>::new_with_rng(
                     &[(pub_key.into(), AuthSecretKey::RpoFalcon512(sec_key))],
@@ -156,9 +167,6 @@ struct PendingObjects {
 
     /// Nullifiers produced in transactions in the block.
     created_nullifiers: Vec<Nullifier>,
-
-    /// Transaction IDs added to the block.
-    included_transactions: Vec<(TransactionId, AccountId)>,
 }
 
 impl PendingObjects {
@@ -167,7 +175,6 @@ impl PendingObjects {
             updated_accounts: vec![],
             output_note_batches: vec![],
             created_nullifiers: vec![],
-            included_transactions: vec![],
         }
     }
 
@@ -342,15 +349,32 @@ impl MockChain {
     pub fn apply_executed_transaction(&mut self, transaction: &ExecutedTransaction) -> Account {
         let mut account = transaction.initial_account().clone();
         account.apply_delta(transaction.account_delta()).unwrap();
+        if let Some(code) = transaction.tx_args().new_account_code() {
+            if transaction.final_account().code_commitment() == code.commitment() {
+                account.upgrade_code(code.clone()).unwrap();
+            }
+        }
 
         // disregard private accounts, so it's easier to retrieve data
         let account_update_details = AccountUpdateDetails::New(account.clone());
 
+        // a block can contain only a single update per account, so transactions against an account
+        // which was already updated in the pending block are merged into the existing update
+        let pending_update = self
+            .pending_objects
+            .updated_accounts
+            .iter()
+            .position(|update| update.account_id() == transaction.account_id())
+            .map(|index| self.pending_objects.updated_accounts.remove(index));
+        let mut transactions =
+            pending_update.map(|update| update.transactions().to_vec()).unwrap_or_default();
+        transactions.push(transaction.id());
+
         let block_account_update = BlockAccountUpdate::new(
             transaction.account_id(),
             account.hash(),
             account_update_details,
-            vec![transaction.id()],
+            transactions,
         );
         self.pending_objects.updated_accounts.push(block_account_update);
 
@@ -363,9 +387,6 @@ impl MockChain {
         // TODO: check that notes are not duplicate
         let output_notes: Vec<OutputNote> = transaction.output_notes().iter().cloned().collect();
         self.pending_objects.output_note_batches.push(output_notes);
-        self.pending_objects
-            .included_transactions
-            .push((transaction.id(), transaction.account_id()));
 
         account
     }
@@ -506,6 +527,16 @@ impl MockChain {
         MockFungibleFaucet(account)
     }
 
+    /// Adds an existing (nonce == 1) account built from the provided [`AccountBuilder`] with the
+    /// specified authentication method.
+    pub fn add_existing_account_from_builder(
+        &mut self,
+        auth_method: Auth,
+        account_builder: AccountBuilder,
+    ) -> Account {
+        self.add_from_account_builder(auth_method, account_builder, AccountState::Exists)
+    }
+
     /// Adds the [`AccountComponent`] corresponding to `auth_method` to the account in the builder
     /// and builds a new or existing account depending on `account_state`.
     ///
@@ -702,8 +733,19 @@ impl MockChain {
             let timestamp = previous.map_or(TIMESTAMP_START_SECS, |block| {
                 block.header().timestamp() + TIMESTAMP_STEP_SECS
             });
-            let tx_hash =
-                compute_tx_hash(self.pending_objects.included_transactions.clone().into_iter());
+
+            // accounts added directly to the mock chain are not the result of any transaction and
+            // are thus not included in the account updates of the block
+            let block_account_updates: Vec<BlockAccountUpdate> = self
+                .pending_objects
+                .updated_accounts
+                .iter()
+                .filter(|update| !update.transactions().is_empty())
+                .cloned()
+                .collect();
+            let tx_hash = compute_tx_hash(block_account_updates.iter().flat_map(|update| {
+                update.transactions().iter().map(|tx_id| (*tx_id, update.account_id()))
+            }));
 
             let kernel_root = TransactionKernel::kernel_root();
 
@@ -726,7 +768,7 @@ impl MockChain {
 
             let block = Block::new(
                 header,
-                self.pending_objects.updated_accounts.clone(),
+                block_account_updates,
                 self.pending_objects.output_note_batches.clone(),
                 self.pending_objects.created_nullifiers.clone(),
             )
@@ -781,8 +823,16 @@ impl MockChain {
     // =========================================================================================
 
     /// Gets the latest [ChainMmr].
+    ///
+    /// The chain MMR tracks all blocks except for the latest one, which is the block the chain MMR
+    /// is built for.
     pub fn chain(&self) -> ChainMmr {
-        let block_headers: Vec<BlockHeader> = self.blocks.iter().map(|b| b.header()).collect();
+        let block_headers: Vec<BlockHeader> = self
+            .blocks
+            .iter()
+            .take(self.blocks.len().saturating_sub(1))
+            .map(|b| b.header())
+            .collect();
         mmr_to_chain_mmr(&self.chain, &block_headers).unwrap()
     }
 
@@ -822,7 +872,7 @@ enum AccountState {
 
 /// Converts the MMR into partial MMR by copying all leaves from MMR to partial MMR.
 fn mmr_to_chain_mmr(mmr: &Mmr, blocks: &[BlockHeader]) -> Result<ChainMmr, MmrError> {
-    let target_forest = mmr.forest() - 1;
+    let target_forest = mmr.forest().saturating_sub(1);
     let mut partial_mmr = PartialMmr::from_peaks(mmr.peaks_at(target_forest)?);
 
     for i in 0..target_forest {
@@ -74,6 +74,7 @@ pub struct TransactionContextBuilder {
     expected_output_notes: Vec<Note>,
     foreign_account_codes: Vec<AccountCode>,
     input_notes: Vec<Note>,
+    new_account_code: Option<AccountCode>,
     tx_script: Option<TransactionScript>,
     note_args: BTreeMap<NoteId, Word>,
     transaction_inputs: Option<TransactionInputs>,
@@ -88,6 +89,7 @@ impl TransactionContextBuilder {
             account_seed: None,
             input_notes: Vec::new(),
             expected_output_notes: Vec::new(),
+            new_account_code: None,
             rng: ChaCha20Rng::from_seed([0_u8; 32]),
             tx_script: None,
             authenticator: None,
@@ -116,6 +118,7 @@ impl TransactionContextBuilder {
             authenticator: None,
             input_notes: Vec::new(),
             expected_output_notes: Vec::new(),
+            new_account_code: None,
             advice_inputs: Default::default(),
             rng: ChaCha20Rng::from_seed([0_u8; 32]),
             tx_script: None,
@@ -185,6 +188,18 @@ impl TransactionContextBuilder {
         self
     }
 
+    /// Set the code the account is upgraded to by the transaction
+    pub fn new_account_code(mut self, code: AccountCode) -> Self {
+        self.new_account_code = Some(code);
+        self
+    }
+
+    /// Extend the set of note args used when consuming the input notes
+    pub fn note_args(mut self, note_args: BTreeMap<NoteId, Word>) -> Self {
+        self.note_args.extend(note_args);
+        self
+    }
+
     /// Set the desired transaction inputs
     pub fn tx_inputs(mut self, tx_inputs: TransactionInputs) -> Self {
         self.transaction_inputs = Some(tx_inputs);
@@ -659,6 +674,11 @@ impl TransactionContextBuilder {
 
         tx_args.extend_expected_output_notes(self.expected_output_notes.clone());
 
+        let tx_args = match self.new_account_code {
+            Some(code) => tx_args.with_new_account_code(code),
+            None => tx_args,
+        };
+
         TransactionContext {
             expected_output_notes: self.expected_output_notes,
             tx_args,
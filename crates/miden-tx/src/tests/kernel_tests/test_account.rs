@@ -2,7 +2,7 @@ use miden_lib::{
     errors::tx_kernel_errors::{
         ERR_ACCOUNT_ID_EPOCH_MUST_BE_LESS_THAN_U16_MAX,
         ERR_ACCOUNT_ID_LEAST_SIGNIFICANT_BYTE_MUST_BE_ZERO, ERR_ACCOUNT_ID_UNKNOWN_STORAGE_MODE,
-        ERR_ACCOUNT_ID_UNKNOWN_VERSION, TX_KERNEL_ERRORS,
+        ERR_ACCOUNT_ID_UNKNOWN_VERSION, ERR_FAUCET_STORAGE_DATA_SLOT_IS_RESERVED, TX_KERNEL_ERRORS,
     },
     transaction::TransactionKernel,
 };
@@ -19,6 +19,7 @@ use miden_objects::{
             ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
             ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN,
         },
+        constants::FUNGIBLE_FAUCET_INITIAL_BALANCE,
         prepare_word,
         storage::STORAGE_LEAVES_2,
     },
@@ -29,7 +30,10 @@ use rand_chacha::ChaCha20Rng;
 use vm_processor::{Digest, ExecutionError, MemAdviceProvider, ProcessState};
 
 use super::{Felt, StackInputs, Word, ONE, ZERO};
-use crate::testing::{executor::CodeExecutor, TransactionContextBuilder};
+use crate::{
+    assert_execution_error,
+    testing::{executor::CodeExecutor, TransactionContextBuilder},
+};
 
 // ACCOUNT CODE TESTS
 // ================================================================================================
@@ -422,6 +426,85 @@ fn test_set_item() {
     tx_context.execute_code(&code).unwrap();
 }
 
+#[test]
+fn test_set_item_faucet_component_first_slot() {
+    // The component's first storage slot is located right after the reserved faucet slot.
+    let source_code = "
+        use.miden::account
+
+        export.write_first_slot
+            push.1.2.3.4.0
+            exec.account::set_item
+            dropw dropw
+
+            push.1 exec.account::incr_nonce
+        end
+    ";
+
+    let component = AccountComponent::compile(
+        source_code,
+        TransactionKernel::testing_assembler(),
+        vec![StorageSlot::Value(Word::default())],
+    )
+    .unwrap()
+    .with_supported_type(AccountType::FungibleFaucet);
+
+    let mut account = AccountBuilder::new(ChaCha20Rng::from_entropy().gen())
+        .account_type(AccountType::FungibleFaucet)
+        .with_component(component.clone())
+        .build_existing()
+        .unwrap();
+
+    let library = component.library();
+    let export = library.exports().next().unwrap();
+    let write_first_slot = library.mast_forest()[library.get_export_node_id(export)].digest();
+
+    let tx_script_program = TransactionKernel::testing_assembler()
+        .assemble_program(format!("begin call.{write_first_slot} end"))
+        .unwrap();
+    let tx_script = TransactionScript::new(tx_script_program, vec![]);
+
+    let tx_context = TransactionContextBuilder::new(account.clone()).tx_script(tx_script).build();
+    let tx = tx_context.execute().unwrap();
+    account.apply_delta(tx.account_delta()).unwrap();
+
+    // the reserved slot is left untouched and the component's first slot has been set
+    assert_eq!(account.storage().get_item(0).unwrap(), Word::default().into());
+    assert_eq!(
+        account.storage().get_item(1).unwrap(),
+        [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)].into()
+    );
+}
+
+#[test]
+fn test_set_item_faucet_reserved_slot_fails() {
+    let tx_context = TransactionContextBuilder::with_fungible_faucet(
+        ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN,
+        ONE,
+        Felt::new(FUNGIBLE_FAUCET_INITIAL_BALANCE),
+    )
+    .build();
+
+    // the procedures of the mock faucet do not have a storage offset, so index 0 points to the
+    // reserved faucet slot
+    let code = "
+        use.test::account
+        use.kernel::prologue
+
+        begin
+            exec.prologue::prepare_transaction
+
+            push.1.2.3.4
+            push.0
+            call.account::set_item
+        end
+        ";
+
+    let process = tx_context.execute_code(code);
+
+    assert_execution_error!(process, ERR_FAUCET_STORAGE_DATA_SLOT_IS_RESERVED);
+}
+
 #[test]
 fn test_set_map_item() {
     let (new_key, new_value) = (
@@ -1074,10 +1074,148 @@ fn test_fpi_execute_foreign_procedure() {
         .unwrap();
 }
 
+/// Test that a storage map item of a foreign account can be read when only the witness of the read
+/// key is provided instead of the entire storage map.
+#[test]
+fn test_fpi_get_map_item_with_storage_map_witness() {
+    let foreign_account_code_source = "
+        use.miden::account
+
+        export.get_map_item_foreign
+            # make this foreign procedure unique to make sure that we invoke the procedure of the
+            # foreign account, not the native one
+            push.3 drop
+            exec.account::get_map_item
+        end
+    ";
+
+    let foreign_account_component = AccountComponent::compile(
+        foreign_account_code_source,
+        TransactionKernel::testing_assembler(),
+        vec![AccountStorage::mock_item_2().slot],
+    )
+    .unwrap()
+    .with_supports_all_types();
+
+    let foreign_account = AccountBuilder::new(ChaCha20Rng::from_entropy().gen())
+        .with_component(foreign_account_component)
+        .build_existing()
+        .unwrap();
+
+    let native_account = AccountBuilder::new(ChaCha20Rng::from_entropy().gen())
+        .with_component(
+            AccountMockComponent::new_with_slots(TransactionKernel::testing_assembler(), vec![])
+                .unwrap(),
+        )
+        .build_existing()
+        .unwrap();
+
+    let mut mock_chain =
+        MockChain::with_accounts(&[native_account.clone(), foreign_account.clone()]);
+    mock_chain.seal_block(None);
+
+    // provide only the witness of the key which is read by the transaction
+    let (map_key, map_value) = STORAGE_LEAVES_2[0];
+    let mut advice_inputs = get_mock_fpi_account_adv_inputs(&foreign_account, &mock_chain);
+    let witnesses = foreign_account.storage().open_map_items([(0, map_key)]).unwrap();
+    TransactionKernel::extend_advice_inputs_for_storage_map_witnesses(
+        &mut advice_inputs,
+        &witnesses,
+    );
+
+    let StorageSlot::Map(foreign_map) = &foreign_account.storage().slots()[0] else {
+        panic!("storage slot 0 should be a map");
+    };
+    let other_leaf = foreign_map.get_leaf(&STORAGE_LEAVES_2[1].0);
+    assert!(advice_inputs.map.get(&other_leaf.hash()).is_none());
+
+    let code = format!(
+        "
+        use.std::sys
+
+        use.miden::tx
+
+        begin
+            # pad the stack for the `execute_foreign_procedure` execution
+            padw padw push.0.0
+            # => [pad(10)]
+
+            # push the key of desired storage item
+            push.{map_key}
+
+            # push the index of desired storage item
+            push.0
+
+            # get the hash of the `get_map_item_foreign` account procedure
+            push.{get_map_item_foreign_hash}
+
+            # push the foreign account ID
+            push.{foreign_suffix}.{foreign_prefix}
+            # => [foreign_account_id_prefix, foreign_account_id_suffix, FOREIGN_PROC_ROOT, storage_item_index, MAP_ITEM_KEY, pad(10)]
+
+            exec.tx::execute_foreign_procedure
+            # => [MAP_VALUE]
+
+            # assert the correctness of the obtained value
+            push.{map_value} assert_eqw
+            # => []
+
+            # truncate the stack
+            exec.sys::truncate_stack
+        end
+        ",
+        foreign_prefix = foreign_account.id().prefix().as_felt(),
+        foreign_suffix = foreign_account.id().suffix(),
+        get_map_item_foreign_hash = foreign_account.code().procedures()[0].mast_root(),
+        map_value = prepare_word(&map_value),
+    );
+
+    let tx_script =
+        TransactionScript::compile(code, vec![], TransactionKernel::testing_assembler()).unwrap();
+
+    let tx_context = mock_chain
+        .build_tx_context(native_account.id(), &[], &[])
+        .advice_inputs(advice_inputs)
+        .tx_script(tx_script)
+        .build();
+
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+
+    let mut executor: TransactionExecutor =
+        TransactionExecutor::new(tx_context.get_data_store(), None).with_tracing();
+    executor.load_account_code(foreign_account.code());
+
+    executor
+        .execute_transaction(native_account.id(), block_ref, &[], tx_context.tx_args().clone())
+        .map_err(|e| e.to_string())
+        .unwrap();
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
 fn get_mock_fpi_adv_inputs(foreign_account: &Account, mock_chain: &MockChain) -> AdviceInputs {
+    let mut advice_inputs = get_mock_fpi_account_adv_inputs(foreign_account, mock_chain);
+
+    for slot in foreign_account.storage().slots() {
+        // if there are storage maps, we populate the merkle store and advice map
+        if let StorageSlot::Map(map) = slot {
+            // extend the merkle store and map with the storage maps
+            advice_inputs.extend_merkle_store(map.inner_nodes());
+            // populate advice map with Sparse Merkle Tree leaf nodes
+            advice_inputs
+                .extend_map(map.leaves().map(|(_, leaf)| (leaf.hash(), leaf.to_elements())));
+        }
+    }
+
+    advice_inputs
+}
+
+/// Returns the advice inputs of the foreign account without the data of its storage maps.
+fn get_mock_fpi_account_adv_inputs(
+    foreign_account: &Account,
+    mock_chain: &MockChain,
+) -> AdviceInputs {
     let mut advice_inputs = AdviceInputs::default();
     TransactionKernel::extend_advice_inputs_for_account(
         &mut advice_inputs,
@@ -1098,17 +1236,6 @@ fn get_mock_fpi_adv_inputs(foreign_account: &Account, mock_chain: &MockChain) ->
     )
     .unwrap();
 
-    for slot in foreign_account.storage().slots() {
-        // if there are storage maps, we populate the merkle store and advice map
-        if let StorageSlot::Map(map) = slot {
-            // extend the merkle store and map with the storage maps
-            advice_inputs.extend_merkle_store(map.inner_nodes());
-            // populate advice map with Sparse Merkle Tree leaf nodes
-            advice_inputs
-                .extend_map(map.leaves().map(|(_, leaf)| (leaf.hash(), leaf.to_elements())));
-        }
-    }
-
     advice_inputs
 }
 
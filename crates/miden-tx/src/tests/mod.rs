@@ -9,14 +9,16 @@ use ::assembly::{
     ast::{Module, ModuleKind},
     LibraryPath,
 };
-use miden_lib::transaction::TransactionKernel;
+use miden_lib::transaction::{
+    ExecutionContextCache, TransactionAssemblerBuilder, TransactionKernel,
+};
 use miden_objects::{
     account::{AccountBuilder, AccountComponent, AccountStorage, StorageSlot},
     assembly::DefaultSourceManager,
     asset::{Asset, AssetVault, FungibleAsset, NonFungibleAsset},
     note::{
         Note, NoteAssets, NoteExecutionHint, NoteExecutionMode, NoteHeader, NoteId, NoteInputs,
-        NoteMetadata, NoteRecipient, NoteScript, NoteTag, NoteType,
+        NoteMetadata, NoteRecipient, NoteRecipientBuilder, NoteScript, NoteTag, NoteType,
     },
     testing::{
         account_component::AccountMockComponent,
@@ -29,7 +31,11 @@ use miden_objects::{
         prepare_word,
         storage::{STORAGE_INDEX_0, STORAGE_INDEX_2},
     },
-    transaction::{ProvenTransaction, TransactionArgs, TransactionScript},
+    transaction::{
+        InputNotes, LogEntry, OutputNote, ProvenTransaction, TransactionArgs, TransactionInputs,
+        TransactionScript,
+    },
+    vm::{AdviceInputs, AdviceMap},
     Felt, Word, MIN_PROOF_SECURITY_LEVEL,
 };
 use miden_prover::ProvingOptions;
@@ -44,7 +50,10 @@ use super::{
     LocalTransactionProver, TransactionExecutor, TransactionHost, TransactionProver,
     TransactionVerifier,
 };
-use crate::{testing::TransactionContextBuilder, TransactionMastStore};
+use crate::{
+    testing::{MockChain, TransactionContextBuilder},
+    TransactionMastStore,
+};
 
 mod kernel_tests;
 
@@ -104,7 +113,7 @@ fn transaction_executor_witness() {
     )
     .unwrap();
 
-    let (advice_provider, _, output_notes, _signatures, _tx_progress) = host.into_parts();
+    let (advice_provider, _, output_notes, _logs, _signatures, _tx_progress) = host.into_parts();
     let (_, map, _) = advice_provider.into_parts();
     let tx_outputs = TransactionKernel::from_transaction_parts(
         result.stack_outputs(),
@@ -117,6 +126,88 @@ fn transaction_executor_witness() {
     assert_eq!(executed_transaction.output_notes(), &tx_outputs.output_notes);
 }
 
+#[test]
+fn prepare_inputs_with_execution_context_cache() {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .with_mock_notes_preserved()
+        .build();
+    let data_store = tx_context.get_data_store();
+    let executed_transaction = tx_context.execute().unwrap();
+    let tx_inputs = executed_transaction.tx_inputs();
+    let tx_args = executed_transaction.tx_args();
+
+    let mut cache = ExecutionContextCache::new();
+    let (_, advice_inputs) =
+        TransactionKernel::prepare_inputs_with_cache(tx_inputs, tx_args, None, &mut cache);
+    let (_, expected_advice_inputs) = TransactionKernel::prepare_inputs(tx_inputs, tx_args, None);
+    assert_eq!(advice_inputs, expected_advice_inputs);
+
+    assert_eq!(cache.account_id(), Some(tx_inputs.account().id()));
+    assert_eq!(cache.block_hash(), Some(tx_inputs.block_header().hash()));
+    assert_eq!(cache.chain_length(), Some(tx_inputs.block_chain().chain_length()));
+    assert_eq!(cache.vault_commitment(), Some(tx_inputs.account().vault().commitment()));
+
+    // prepare the inputs of a subsequent transaction against the updated account and the same
+    // reference block
+    let mut account = tx_inputs.account().clone();
+    account.apply_delta(executed_transaction.account_delta()).unwrap();
+    let next_tx_inputs = TransactionInputs::new(
+        account,
+        None,
+        *tx_inputs.block_header(),
+        tx_inputs.block_chain().clone(),
+        InputNotes::default(),
+    )
+    .unwrap();
+    let next_tx_args = TransactionArgs::default();
+
+    let (_, advice_inputs) = TransactionKernel::prepare_inputs_with_cache(
+        &next_tx_inputs,
+        &next_tx_args,
+        None,
+        &mut cache,
+    );
+    let (_, expected_advice_inputs) =
+        TransactionKernel::prepare_inputs(&next_tx_inputs, &next_tx_args, None);
+    assert_eq!(advice_inputs, expected_advice_inputs);
+    assert_eq!(cache.vault_commitment(), Some(next_tx_inputs.account().vault().commitment()));
+
+    // preparing the inputs of the same transaction again reuses the cached data as is
+    let (_, advice_inputs) = TransactionKernel::prepare_inputs_with_cache(
+        &next_tx_inputs,
+        &next_tx_args,
+        None,
+        &mut cache,
+    );
+    assert_eq!(advice_inputs, expected_advice_inputs);
+
+    // the account data replaces colliding entries of the initial advice inputs, as when preparing
+    // the inputs without a cache
+    let code_commitment = next_tx_inputs.account().code().commitment();
+    let init_advice_inputs = AdviceInputs::default().with_map([(code_commitment, vec![ONE])]);
+    let (_, advice_inputs) = TransactionKernel::prepare_inputs_with_cache(
+        &next_tx_inputs,
+        &next_tx_args,
+        Some(init_advice_inputs.clone()),
+        &mut cache,
+    );
+    let (_, expected_advice_inputs) =
+        TransactionKernel::prepare_inputs(&next_tx_inputs, &next_tx_args, Some(init_advice_inputs));
+    assert_eq!(advice_inputs, expected_advice_inputs);
+    assert_ne!(advice_inputs.mapped_values(&code_commitment), Some([ONE].as_slice()));
+
+    // an executor with an execution cache produces the same transaction on repeated executions
+    let executor = TransactionExecutor::new(data_store, None).with_execution_cache();
+    let block_ref = tx_inputs.block_header().block_num();
+    let note_ids = tx_inputs.input_notes().iter().map(|note| note.id()).collect::<Vec<_>>();
+    for _ in 0..2 {
+        let cached_transaction = executor
+            .execute_transaction(tx_inputs.account().id(), block_ref, &note_ids, tx_args.clone())
+            .unwrap();
+        assert_eq!(cached_transaction.final_account(), executed_transaction.final_account());
+    }
+}
+
 #[test]
 fn executed_transaction_account_delta_new() {
     let account_assets = AssetVault::mock().assets().collect::<Vec<Asset>>();
@@ -798,6 +889,140 @@ fn executed_transaction_output_notes() {
     assert_eq!(expected_output_note_3.assets(), output_note_3.assets().unwrap());
 }
 
+#[test]
+fn executed_transaction_output_notes_from_expected_recipient() {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .with_mock_notes_preserved_with_account_vault_delta()
+        .build();
+
+    let executor = TransactionExecutor::new(tx_context.get_data_store(), None).with_debug_mode();
+    let account_id = tx_context.tx_inputs().account().id();
+
+    let asset_1 = NonFungibleAsset::mock(&NON_FUNGIBLE_ASSET_DATA);
+    let asset_2 = FungibleAsset::mock(FUNGIBLE_ASSET_AMOUNT / 2);
+    let tag = NoteTag::from_account_id(
+        ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN.try_into().unwrap(),
+        NoteExecutionMode::Local,
+    )
+    .unwrap();
+    let aux = Felt::new(27);
+    let note_type = NoteType::Private;
+
+    // In this test we create 2 notes. The recipient of Note 1 is registered with the transaction
+    // arguments, so the note is output in full. The recipient of Note 2 is not known to the
+    // executor, so the note is output as a partial note.
+    let note_script =
+        NoteScript::compile(DEFAULT_NOTE_CODE, TransactionKernel::testing_assembler()).unwrap();
+    let recipient_1 = NoteRecipientBuilder::new(
+        Word::from([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]),
+        note_script.clone(),
+    )
+    .add_input(Felt::new(5))
+    .build()
+    .unwrap();
+    let recipient_2 = NoteRecipientBuilder::new(
+        Word::from([Felt::new(6), Felt::new(7), Felt::new(8), Felt::new(9)]),
+        note_script,
+    )
+    .add_inputs([Felt::new(10), Felt::new(11)])
+    .build()
+    .unwrap();
+
+    let tx_script_src = format!(
+        "\
+        use.miden::contracts::wallets::basic->wallet
+        use.test::account
+
+        begin
+            # create Note 1 and move asset 1 into it
+            push.{RECIPIENT1}
+            push.{EXECUTION_HINT}
+            push.{NOTETYPE}
+            push.{aux}
+            push.{tag}
+            padw padw swapdw
+            call.wallet::create_note
+            # => [note_idx, pad(15)]
+
+            push.{ASSET1}
+            call.wallet::move_asset_to_note
+            dropw dropw dropw dropw dropw
+            # => []
+
+            # create Note 2 and move asset 2 into it
+            push.{RECIPIENT2}
+            push.{EXECUTION_HINT}
+            push.{NOTETYPE}
+            push.{aux}
+            push.{tag}
+            padw padw swapdw
+            call.wallet::create_note
+            # => [note_idx, pad(15)]
+
+            push.{ASSET2}
+            call.wallet::move_asset_to_note
+            dropw dropw dropw dropw dropw
+            # => []
+
+            push.1 call.account::incr_nonce drop
+            # => []
+        end
+    ",
+        RECIPIENT1 = prepare_word(&Word::from(recipient_1.digest())),
+        RECIPIENT2 = prepare_word(&Word::from(recipient_2.digest())),
+        ASSET1 = prepare_word(&Word::from(asset_1)),
+        ASSET2 = prepare_word(&Word::from(asset_2)),
+        NOTETYPE = note_type as u8,
+        EXECUTION_HINT = Felt::from(NoteExecutionHint::always()),
+    );
+
+    let tx_script = TransactionScript::compile(
+        tx_script_src,
+        [],
+        TransactionKernel::testing_assembler_with_mock_account().with_debug_mode(true),
+    )
+    .unwrap();
+    let mut tx_args = TransactionArgs::new(
+        Some(tx_script),
+        None,
+        tx_context.tx_args().advice_inputs().clone().map,
+    );
+    tx_args.add_expected_output_recipient(&recipient_1);
+
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+    let note_ids = tx_context
+        .tx_inputs()
+        .input_notes()
+        .iter()
+        .map(|note| note.id())
+        .collect::<Vec<_>>();
+
+    let executed_transaction =
+        executor.execute_transaction(account_id, block_ref, &note_ids, tx_args).unwrap();
+
+    // NOTE: the mock state already contains 3 output notes
+    let output_notes = executed_transaction.output_notes();
+    assert_eq!(output_notes.num_notes(), 5);
+
+    let metadata =
+        NoteMetadata::new(account_id, note_type, tag, NoteExecutionHint::always(), aux).unwrap();
+
+    // assert that Note 1 was built from the registered recipient
+    let expected_note_1 =
+        Note::new(NoteAssets::new(vec![asset_1]).unwrap(), metadata, recipient_1.clone());
+    assert_eq!(output_notes.get_note(3), &OutputNote::Full(expected_note_1));
+
+    // assert that Note 2 is partial and can be completed from its recipient
+    let OutputNote::Partial(partial_note_2) = output_notes.get_note(4) else {
+        panic!("note 2 should be a partial note");
+    };
+    assert!(partial_note_2.clone().complete(recipient_1).is_err());
+
+    let expected_note_2 =
+        Note::new(NoteAssets::new(vec![asset_2]).unwrap(), metadata, recipient_2.clone());
+    assert_eq!(partial_note_2.clone().complete(recipient_2).unwrap(), expected_note_2);
+}
+
 #[test]
 fn prove_witness_and_verify() {
     let tx_context = TransactionContextBuilder::with_standard_account(ONE)
@@ -893,6 +1118,67 @@ fn test_tx_script() {
     );
 }
 
+#[test]
+fn test_tx_script_emit_log() {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE).build();
+    let executor = TransactionExecutor::new(tx_context.get_data_store(), None);
+
+    let account_id = tx_context.tx_inputs().account().id();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+
+    let topic = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+    let data_1 = [Felt::new(5), Felt::new(6), Felt::new(7), Felt::new(8)];
+    let data_2 = [Felt::new(9), Felt::new(10), Felt::new(11), Felt::new(12)];
+    let tx_script_src = format!(
+        "
+    use.miden::tx
+
+    begin
+        push.{data_1} push.{topic}
+        # => [TOPIC, DATA_1]
+
+        exec.tx::emit_log
+        # => []
+
+        push.{data_2} push.{topic}
+        # => [TOPIC, DATA_2]
+
+        exec.tx::emit_log
+        # => []
+    end
+",
+        topic = prepare_word(&topic),
+        data_1 = prepare_word(&data_1),
+        data_2 = prepare_word(&data_2),
+    );
+
+    let tx_script =
+        TransactionScript::compile(tx_script_src, [], TransactionKernel::testing_assembler())
+            .unwrap();
+    let tx_script_root = tx_script.hash();
+    let tx_args = TransactionArgs::new(Some(tx_script), None, AdviceMap::default());
+
+    let executed_transaction =
+        executor.execute_transaction(account_id, block_ref, &[], tx_args).unwrap();
+
+    let logs = executed_transaction.logs().clone();
+    assert_eq!(
+        logs.iter().copied().collect::<Vec<_>>(),
+        vec![
+            LogEntry::new(tx_script_root, topic, data_1),
+            LogEntry::new(tx_script_root, topic, data_2)
+        ]
+    );
+
+    let proven_transaction = LocalTransactionProver::new(ProvingOptions::default())
+        .prove(executed_transaction.into())
+        .unwrap();
+    assert_eq!(proven_transaction.logs_commitment(), logs.commitment());
+
+    let verifier = TransactionVerifier::new(MIN_PROOF_SECURITY_LEVEL);
+    assert!(verifier.verify(proven_transaction).is_ok());
+}
+
 /// Tests that an account can call code in a custom library when loading that library into the
 /// executor.
 ///
@@ -926,8 +1212,8 @@ fn transaction_executor_account_code_using_custom_library() {
         .assemble_library([external_library_module])
         .unwrap();
 
-    let mut assembler = TransactionKernel::assembler();
-    assembler.add_library(&external_library).unwrap();
+    let assembler =
+        TransactionKernel::assembler_with_libraries([external_library.clone()]).unwrap();
 
     let account_component_module = Module::parser(ModuleKind::Library)
         .parse_str(
@@ -937,8 +1223,7 @@ fn transaction_executor_account_code_using_custom_library() {
         )
         .unwrap();
 
-    let account_component_lib =
-        assembler.clone().assemble_library([account_component_module]).unwrap();
+    let account_component_lib = assembler.assemble_library([account_component_module]).unwrap();
 
     let tx_script_src = "\
           use.account_component::account_module
@@ -965,7 +1250,10 @@ fn transaction_executor_account_code_using_custom_library() {
         [],
         // Add the account component library since the transaction script is calling the account's
         // procedure.
-        assembler.with_library(&account_component_lib).unwrap(),
+        TransactionAssemblerBuilder::new()
+            .with_libraries([external_library.clone(), account_component_lib])
+            .build()
+            .unwrap(),
     )
     .unwrap();
 
@@ -988,3 +1276,18 @@ fn transaction_executor_account_code_using_custom_library() {
     // Account's initial nonce of 1 should have been incremented by 4.
     assert_eq!(executed_tx.account_delta().nonce().unwrap(), Felt::new(5));
 }
+
+#[test]
+fn mock_chain_chain_mmr_excludes_latest_block() {
+    // an empty mock chain must produce an empty chain MMR instead of panicking
+    let mock_chain = MockChain::empty();
+    assert_eq!(mock_chain.chain().chain_length(), 0.into());
+
+    let mut mock_chain = MockChain::new();
+    let latest_block = mock_chain.seal_block(None);
+
+    // the chain MMR tracks all blocks before the latest one
+    let chain_mmr = mock_chain.chain();
+    assert_eq!(chain_mmr.chain_length(), latest_block.header().block_num());
+    assert!(chain_mmr.get_block(latest_block.header().block_num()).is_none());
+}
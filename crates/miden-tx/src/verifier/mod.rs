@@ -42,6 +42,7 @@ impl TransactionVerifier {
             transaction.account_update().final_state_hash(),
             transaction.output_notes().commitment(),
             transaction.expiration_block_num(),
+            transaction.logs_commitment(),
         );
 
         // verify transaction proof
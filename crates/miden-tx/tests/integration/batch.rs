@@ -0,0 +1,298 @@
+use std::collections::BTreeMap;
+
+use assert_matches::assert_matches;
+use miden_lib::{note::create_p2id_note, transaction::TransactionKernel};
+use miden_objects::{
+    account::AccountId,
+    asset::FungibleAsset,
+    batch::{ProposedBatch, TransactionDependencyGraph},
+    block::BlockNumber,
+    crypto::rand::RpoRandomCoin,
+    note::{Note, NoteType},
+    testing::{
+        account_id::{
+            ACCOUNT_ID_OFF_CHAIN_SENDER, ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN,
+            ACCOUNT_ID_SENDER,
+        },
+        note::NoteBuilder,
+        prepare_word,
+    },
+    transaction::{
+        ExecutedTransaction, InputNote, InputNoteCommitment, OutputNote, ProvenTransaction,
+        ProvenTransactionBuilder, TransactionId, TransactionScript,
+    },
+    vm::ExecutionProof,
+    Digest, Felt, ProposedBatchError, TransactionDependencyError,
+};
+use miden_prover::{ProvingOptions, StackInputs};
+use miden_tx::{
+    testing::{Auth, MockChain},
+    LocalTransactionProver, TransactionProver,
+};
+use rand_chacha::{rand_core::SeedableRng, ChaCha20Rng};
+use vm_processor::DefaultHost;
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn prove_transaction(executed_transaction: ExecutedTransaction) -> ProvenTransaction {
+    LocalTransactionProver::new(ProvingOptions::default())
+        .prove(executed_transaction.into())
+        .unwrap()
+}
+
+/// Returns the proof of a trivial program which is used to build proven transactions whose
+/// account state transitions and notes are set up directly.
+fn mock_execution_proof() -> ExecutionProof {
+    let program = TransactionKernel::testing_assembler()
+        .assemble_program("begin push.1 drop end")
+        .unwrap();
+    let (_, proof) = miden_prover::prove(
+        &program,
+        StackInputs::default(),
+        &mut DefaultHost::default(),
+        ProvingOptions::default(),
+    )
+    .unwrap();
+
+    proof
+}
+
+fn state(value: u64) -> Digest {
+    Digest::from([Felt::new(value); 4])
+}
+
+fn mock_note(rng: &mut ChaCha20Rng) -> Note {
+    let sender = AccountId::try_from(ACCOUNT_ID_OFF_CHAIN_SENDER).unwrap();
+    NoteBuilder::new(sender, rng)
+        .build(&TransactionKernel::testing_assembler())
+        .unwrap()
+}
+
+fn mock_tx(
+    proof: &ExecutionProof,
+    account_id: u128,
+    transition: (u64, u64),
+    consumed_notes: &[&Note],
+    created_notes: &[&Note],
+) -> ProvenTransaction {
+    ProvenTransactionBuilder::new(
+        AccountId::try_from(account_id).unwrap(),
+        state(transition.0),
+        state(transition.1),
+        Digest::default(),
+        BlockNumber::from(1),
+        proof.clone(),
+    )
+    .add_input_notes(
+        consumed_notes
+            .iter()
+            .map(|&note| InputNoteCommitment::from(InputNote::unauthenticated(note.clone()))),
+    )
+    .add_output_notes(created_notes.iter().map(|&note| OutputNote::Header(*note.header())))
+    .build()
+    .unwrap()
+}
+
+// TESTS
+// ================================================================================================
+
+/// Tests that a note created by one transaction of a batch and consumed as an unauthenticated note
+/// by another transaction of the same batch is erased from the batch, while the remaining
+/// unauthenticated notes are authenticated using their inclusion proofs.
+#[test]
+fn proposed_batch_erases_notes_created_in_batch() {
+    let mut mock_chain = MockChain::new();
+    let sender = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![FungibleAsset::mock(20)]);
+    let target = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+
+    let chain_note = mock_chain
+        .add_p2id_note(
+            ACCOUNT_ID_SENDER.try_into().unwrap(),
+            target.id(),
+            &[FungibleAsset::mock(5)],
+            NoteType::Public,
+            None,
+        )
+        .unwrap();
+    mock_chain.seal_block(None);
+    // seal another block so that the block containing the note is tracked by the chain MMR
+    mock_chain.seal_block(None);
+
+    // the sender creates a note for the target
+    let batch_note = create_p2id_note(
+        sender.id(),
+        target.id(),
+        vec![FungibleAsset::mock(10)],
+        NoteType::Public,
+        Felt::new(0),
+        &mut RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]),
+    )
+    .unwrap();
+
+    let tx_script_src = format!(
+        "
+        begin
+            push.{recipient}
+            push.{note_execution_hint}
+            push.{note_type}
+            push.0              # aux
+            push.{tag}
+            call.::miden::contracts::wallets::basic::create_note
+
+            push.{asset}
+            call.::miden::contracts::wallets::basic::move_asset_to_note
+            call.::miden::contracts::auth::basic::auth_tx_rpo_falcon512
+            dropw dropw dropw dropw
+        end
+        ",
+        recipient = prepare_word(&batch_note.recipient().digest()),
+        note_execution_hint = Felt::from(batch_note.metadata().execution_hint()),
+        note_type = NoteType::Public as u8,
+        tag = Felt::from(batch_note.metadata().tag()),
+        asset = prepare_word(&FungibleAsset::mock(10).into()),
+    );
+    let tx_script =
+        TransactionScript::compile(tx_script_src, vec![], TransactionKernel::testing_assembler())
+            .unwrap();
+
+    let sender_tx = mock_chain
+        .build_tx_context(sender.id(), &[], &[])
+        .expected_notes(vec![OutputNote::Full(batch_note.clone())])
+        .tx_script(tx_script)
+        .build()
+        .execute()
+        .unwrap();
+    let sender_tx = prove_transaction(sender_tx);
+
+    // the target consumes both notes as unauthenticated notes
+    let target_tx = mock_chain
+        .build_tx_context(target.id(), &[], &[batch_note.clone(), chain_note.clone()])
+        .build()
+        .execute()
+        .unwrap();
+    let target_tx = prove_transaction(target_tx);
+
+    let chain_note_proof = mock_chain
+        .available_notes()
+        .into_iter()
+        .find(|note| note.id() == chain_note.id())
+        .and_then(|note| note.proof().cloned())
+        .unwrap();
+    let note_proofs = BTreeMap::from([(chain_note.id(), chain_note_proof)]);
+
+    let batch = ProposedBatch::new(
+        vec![sender_tx.clone(), target_tx.clone()],
+        &mock_chain.chain(),
+        &note_proofs,
+    )
+    .unwrap();
+
+    assert_eq!(batch.transactions().len(), 2);
+    assert_eq!(batch.input_notes(), [InputNoteCommitment::from(chain_note.nullifier())]);
+    assert!(batch.output_notes().is_empty());
+    assert_eq!(batch.erased_notes().len(), 1);
+    assert_eq!(batch.erased_notes()[0].id(), batch_note.id());
+
+    // the note which was not created in the batch must have an inclusion proof
+    let result = ProposedBatch::new(
+        vec![sender_tx.clone(), target_tx.clone()],
+        &mock_chain.chain(),
+        &BTreeMap::new(),
+    );
+    assert!(matches!(
+        result,
+        Err(ProposedBatchError::UnauthenticatedNoteMissingInclusionProof(note_id))
+            if note_id == chain_note.id()
+    ));
+
+    // without the creating transaction the batch note is an ordinary unauthenticated note
+    let result = ProposedBatch::new(vec![target_tx], &mock_chain.chain(), &note_proofs);
+    assert!(matches!(
+        result,
+        Err(ProposedBatchError::UnauthenticatedNoteMissingInclusionProof(note_id))
+            if note_id == batch_note.id()
+    ));
+
+    let batch = ProposedBatch::new(vec![sender_tx], &mock_chain.chain(), &BTreeMap::new()).unwrap();
+    assert!(batch.input_notes().is_empty());
+    assert_eq!(batch.output_notes().len(), 1);
+    assert_eq!(batch.output_notes()[0].id(), batch_note.id());
+}
+
+#[test]
+fn transaction_dependency_graph_ordering() {
+    let proof = mock_execution_proof();
+    let mut rng = ChaCha20Rng::from_seed([0; 32]);
+    let note_1 = mock_note(&mut rng);
+    let note_2 = mock_note(&mut rng);
+
+    // account A transitions from state 0 to state 2, creating a note consumed by account B,
+    // and a transaction which does not change the state of account A is executed in state 1
+    let account_a = ACCOUNT_ID_OFF_CHAIN_SENDER;
+    let account_b = ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN;
+    let tx_a1 = mock_tx(&proof, account_a, (0, 1), &[], &[&note_1]);
+    let tx_a2 = mock_tx(&proof, account_a, (1, 1), &[], &[&note_2]);
+    let tx_a3 = mock_tx(&proof, account_a, (1, 2), &[], &[]);
+    let tx_b1 = mock_tx(&proof, account_b, (10, 11), &[&note_1], &[]);
+
+    let graph = TransactionDependencyGraph::new(vec![
+        tx_b1.clone(),
+        tx_a3.clone(),
+        tx_a2.clone(),
+        tx_a1.clone(),
+    ])
+    .unwrap();
+
+    assert_eq!(graph.dependencies(tx_a1.id()).unwrap(), []);
+    assert_eq!(graph.dependencies(tx_a2.id()).unwrap(), [tx_a1.id()]);
+    assert_eq!(graph.dependencies(tx_b1.id()).unwrap(), [tx_a1.id()]);
+    let mut tx_a3_dependencies = graph.dependencies(tx_a3.id()).unwrap();
+    tx_a3_dependencies.sort();
+    let mut expected_dependencies = vec![tx_a1.id(), tx_a2.id()];
+    expected_dependencies.sort();
+    assert_eq!(tx_a3_dependencies, expected_dependencies);
+
+    let ordered: Vec<TransactionId> =
+        graph.into_ordered().unwrap().iter().map(ProvenTransaction::id).collect();
+    assert_eq!(ordered, [tx_a1.id(), tx_b1.id(), tx_a2.id(), tx_a3.id()]);
+}
+
+#[test]
+fn transaction_dependency_graph_errors() {
+    let proof = mock_execution_proof();
+    let mut rng = ChaCha20Rng::from_seed([0; 32]);
+    let note = mock_note(&mut rng);
+    let account_id = AccountId::try_from(ACCOUNT_ID_OFF_CHAIN_SENDER).unwrap();
+
+    let tx_1 = mock_tx(&proof, ACCOUNT_ID_OFF_CHAIN_SENDER, (0, 1), &[], &[]);
+    let err = TransactionDependencyGraph::new(vec![tx_1.clone(), tx_1.clone()]).unwrap_err();
+    assert_matches!(err, TransactionDependencyError::DuplicateTransaction(id) if id == tx_1.id());
+
+    // two transactions change the account from the same state
+    let tx_2 = mock_tx(&proof, ACCOUNT_ID_OFF_CHAIN_SENDER, (0, 2), &[], &[&note]);
+    let err = TransactionDependencyGraph::new(vec![tx_1.clone(), tx_2]).unwrap_err();
+    assert_matches!(
+        err,
+        TransactionDependencyError::ConflictingAccountStateTransitions { account_id: id, initial_state, .. }
+            if id == account_id && initial_state == state(0)
+    );
+
+    // the account transitions start from two different states
+    let tx_3 = mock_tx(&proof, ACCOUNT_ID_OFF_CHAIN_SENDER, (5, 6), &[], &[]);
+    let err = TransactionDependencyGraph::new(vec![tx_1.clone(), tx_3]).unwrap_err();
+    assert_matches!(
+        err,
+        TransactionDependencyError::DisconnectedAccountStateTransitions { first, second, .. }
+            if first == state(0) && second == state(5)
+    );
+
+    // the account transitions form a cycle
+    let tx_4 = mock_tx(&proof, ACCOUNT_ID_OFF_CHAIN_SENDER, (1, 0), &[], &[]);
+    let graph = TransactionDependencyGraph::new(vec![tx_1.clone(), tx_4.clone()]).unwrap();
+    assert_matches!(
+        graph.into_ordered().unwrap_err(),
+        TransactionDependencyError::DependencyCycle(transactions)
+            if transactions == [tx_1.id(), tx_4.id()]
+    );
+}
@@ -1,5 +1,6 @@
 extern crate alloc;
 
+mod batch;
 mod scripts;
 mod wallet;
 
@@ -11,6 +12,7 @@ use miden_objects::{
     note::{Note, NoteAssets, NoteInputs, NoteMetadata, NoteRecipient, NoteScript, NoteType},
     testing::account_id::ACCOUNT_ID_SENDER,
     transaction::{ExecutedTransaction, ProvenTransaction},
+    utils::serde::CompressedSerializable,
     Felt, Word, ZERO,
 };
 use miden_prover::ProvingOptions;
@@ -58,6 +60,13 @@ pub fn prove_and_verify_transaction(
     let serialised_transaction = proven_transaction.to_bytes();
     let proven_transaction = ProvenTransaction::read_from_bytes(&serialised_transaction).unwrap();
 
+    // Compress & decompress the ProvenTransaction
+    let compressed_transaction = proven_transaction.to_compressed_bytes();
+    assert_eq!(
+        ProvenTransaction::read_from_compressed_bytes(&compressed_transaction).unwrap(),
+        proven_transaction
+    );
+
     // Verify that the generated proof is valid
     let verifier = TransactionVerifier::new(miden_objects::MIN_PROOF_SECURITY_LEVEL);
 
@@ -0,0 +1,123 @@
+use miden_lib::{
+    account::blob::BlobStorage, errors::tx_kernel_errors::ERR_BLOB_STORAGE_CHUNK_COUNT_MISMATCH,
+    transaction::TransactionKernel,
+};
+use miden_objects::{
+    account::{Account, AccountBuilder},
+    testing::prepare_word,
+    transaction::TransactionScript,
+    Felt, FieldElement, EMPTY_WORD,
+};
+use miden_tx::testing::{Auth, MockChain};
+
+use crate::assert_transaction_executor_error;
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns a transaction script which writes `blob` into the blob storage of the executing account.
+///
+/// If `byte_len` is provided, it is used as the byte length of the blob instead of its actual
+/// length.
+fn write_blob_script(blob: &BlobStorage, byte_len: Option<usize>) -> TransactionScript {
+    let (commitment, chunk_elements) = blob.advice_map_entry();
+    let num_chunks = blob.chunks().len();
+
+    let tx_script_code = format!(
+        "
+        begin
+            # pad the stack before call
+            padw padw push.0.0
+            push.{byte_len}.{num_chunks}
+            push.{commitment}
+            # => [BLOB_COMMITMENT, num_chunks, byte_len, pad(10)]
+
+            call.::miden::contracts::blob::basic::write_blob
+            # => [pad(16)]
+
+            call.::miden::contracts::auth::basic::auth_tx_rpo_falcon512
+            # => [pad(16)]
+
+            # truncate the stack
+            dropw dropw dropw dropw
+        end
+        ",
+        byte_len = byte_len.unwrap_or(blob.blob().len()),
+        commitment = prepare_word(&commitment.into()),
+    );
+
+    TransactionScript::compile(
+        tx_script_code,
+        [(commitment.into(), chunk_elements)],
+        TransactionKernel::testing_assembler(),
+    )
+    .unwrap()
+}
+
+/// Reads the blob stored in the blob storage of the provided account.
+///
+/// The blob storage component is added before the auth component, so its first slot has index 0.
+fn read_blob(account: &Account) -> Vec<u8> {
+    BlobStorage::read_from_storage(account.storage(), 0).unwrap().blob().to_vec()
+}
+
+// TESTS
+// ================================================================================================
+
+#[test]
+fn blob_storage_write_and_overwrite_blob() {
+    let mut mock_chain = MockChain::new();
+    let account = mock_chain.add_existing_account_from_builder(
+        Auth::BasicAuth,
+        AccountBuilder::new([3; 32]).with_component(BlobStorage::empty()),
+    );
+    assert!(read_blob(&account).is_empty());
+
+    // write a blob consisting of multiple chunks
+    let blob = BlobStorage::new((0..=100).collect()).unwrap();
+    let executed_transaction = mock_chain
+        .build_tx_context(account.id(), &[], &[])
+        .tx_script(write_blob_script(&blob, None))
+        .build()
+        .execute()
+        .unwrap();
+
+    let account = mock_chain.apply_executed_transaction(&executed_transaction);
+    mock_chain.seal_block(None);
+
+    assert_eq!(read_blob(&account), blob.blob());
+    assert_eq!(account.storage().get_item(1).unwrap(), blob.commitment());
+
+    // overwrite it with a shorter blob, which should also remove the chunks of the previous blob
+    let blob = BlobStorage::new(b"miden".to_vec()).unwrap();
+    let executed_transaction = mock_chain
+        .build_tx_context(account.id(), &[], &[])
+        .tx_script(write_blob_script(&blob, None))
+        .build()
+        .execute()
+        .unwrap();
+
+    let account = mock_chain.apply_executed_transaction(&executed_transaction);
+
+    assert_eq!(read_blob(&account), blob.blob());
+    let second_chunk_key = [Felt::ZERO, Felt::ZERO, Felt::ZERO, Felt::ONE];
+    assert_eq!(account.storage().get_map_item(2, second_chunk_key).unwrap(), EMPTY_WORD);
+}
+
+#[test]
+fn blob_storage_write_fails_on_chunk_count_mismatch() {
+    let mut mock_chain = MockChain::new();
+    let account = mock_chain.add_existing_account_from_builder(
+        Auth::BasicAuth,
+        AccountBuilder::new([3; 32]).with_component(BlobStorage::empty()),
+    );
+
+    let blob = BlobStorage::new((0..40).collect()).unwrap();
+    let execution_result = mock_chain
+        .build_tx_context(account.id(), &[], &[])
+        .tx_script(write_blob_script(&blob, Some(60)))
+        .build()
+        .execute();
+
+    assert_transaction_executor_error!(execution_result, ERR_BLOB_STORAGE_CHUNK_COUNT_MISMATCH);
+}
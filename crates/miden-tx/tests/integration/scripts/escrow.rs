@@ -0,0 +1,273 @@
+use std::collections::BTreeMap;
+
+use miden_lib::{
+    errors::tx_kernel_errors::{
+        ERR_ESCROW_CONSUMER_IS_NOT_ARBITER, ERR_ESCROW_CONSUMER_IS_NOT_BENEFICIARY,
+        ERR_ESCROW_INVALID_SPLIT_AMOUNT, ERR_ESCROW_REFUND_HEIGHT_NOT_REACHED,
+    },
+    note::{
+        create_escrow_note,
+        utils::{build_escrow_release_message, build_escrow_signature_advice_entry, EscrowAction},
+    },
+};
+use miden_objects::{
+    account::{Account, AccountId},
+    asset::{Asset, FungibleAsset},
+    crypto::{dsa::rpo_falcon512::SecretKey, rand::RpoRandomCoin},
+    note::{
+        Note, NoteAssets, NoteExecutionHint, NoteExecutionMode, NoteId, NoteMetadata,
+        NoteRecipient, NoteTag, NoteType,
+    },
+    transaction::OutputNote,
+    vm::AdviceInputs,
+    Word, ZERO,
+};
+use miden_tx::{
+    auth::signatures::get_falcon_signature,
+    testing::{Auth, MockChain},
+};
+use rand_chacha::{rand_core::SeedableRng, ChaCha20Rng};
+
+use crate::assert_transaction_executor_error;
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+struct EscrowSetup {
+    mock_chain: MockChain,
+    sender: Account,
+    beneficiary: Account,
+    arbiter: Account,
+    arbiter_key: SecretKey,
+    note: Note,
+    beneficiary_recipient: NoteRecipient,
+    sender_recipient: NoteRecipient,
+}
+
+/// Creates an escrow note holding a single fungible asset with an amount of 100 which can be
+/// refunded from block 10 onwards.
+fn setup_escrow() -> EscrowSetup {
+    let mut rng = ChaCha20Rng::from_seed([0; 32]);
+    let arbiter_key = SecretKey::with_rng(&mut rng);
+
+    let mut mock_chain = MockChain::new();
+    let sender = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+    let beneficiary = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+    let arbiter = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+
+    let (note, beneficiary_recipient, sender_recipient) = create_escrow_note(
+        sender.id(),
+        beneficiary.id(),
+        arbiter.id(),
+        arbiter_key.public_key(),
+        vec![FungibleAsset::mock(100)],
+        NoteType::Public,
+        ZERO,
+        10.into(),
+        &mut RpoRandomCoin::new(Word::default()),
+    )
+    .unwrap();
+
+    mock_chain.add_pending_note(note.clone());
+    mock_chain.seal_block(None);
+
+    EscrowSetup {
+        mock_chain,
+        sender,
+        beneficiary,
+        arbiter,
+        arbiter_key,
+        note,
+        beneficiary_recipient,
+        sender_recipient,
+    }
+}
+
+/// Returns the P2ID note the arbiter of an escrow note is expected to create for the target.
+fn p2id_note(
+    arbiter_id: AccountId,
+    target_id: AccountId,
+    recipient: NoteRecipient,
+    asset: Asset,
+) -> Note {
+    let tag = NoteTag::from_account_id(target_id, NoteExecutionMode::Local).unwrap();
+    let metadata =
+        NoteMetadata::new(arbiter_id, NoteType::Public, tag, NoteExecutionHint::always(), ZERO)
+            .unwrap();
+
+    Note::new(NoteAssets::new(vec![asset]).unwrap(), metadata, recipient)
+}
+
+/// Returns the note args selecting the provided spend path of the escrow note.
+fn escrow_note_args(note: &Note, action: EscrowAction) -> BTreeMap<NoteId, Word> {
+    BTreeMap::from([(note.id(), action.note_args())])
+}
+
+// TESTS
+// ================================================================================================
+
+#[test]
+fn escrow_release_with_arbiter_signature() {
+    let EscrowSetup {
+        mut mock_chain,
+        sender,
+        beneficiary,
+        arbiter_key,
+        note,
+        ..
+    } = setup_escrow();
+
+    let mut rng = ChaCha20Rng::from_seed([1; 32]);
+    let signature = get_falcon_signature(
+        &arbiter_key,
+        build_escrow_release_message(note.serial_num()),
+        &mut rng,
+    )
+    .unwrap();
+    let advice_inputs = AdviceInputs::default().with_map([build_escrow_signature_advice_entry(
+        arbiter_key.public_key(),
+        note.serial_num(),
+        signature,
+    )]);
+
+    // the sender can not release the note to itself even with the arbiter's signature
+    let execution_result = mock_chain
+        .build_tx_context(sender.id(), &[note.id()], &[])
+        .note_args(escrow_note_args(&note, EscrowAction::Release))
+        .advice_inputs(advice_inputs.clone())
+        .build()
+        .execute();
+
+    assert_transaction_executor_error!(execution_result, ERR_ESCROW_CONSUMER_IS_NOT_BENEFICIARY);
+
+    // the beneficiary receives the assets with the arbiter's signature
+    let executed_transaction = mock_chain
+        .build_tx_context(beneficiary.id(), &[note.id()], &[])
+        .note_args(escrow_note_args(&note, EscrowAction::Release))
+        .advice_inputs(advice_inputs)
+        .build()
+        .execute()
+        .unwrap();
+
+    let beneficiary = mock_chain.apply_executed_transaction(&executed_transaction);
+    assert_eq!(beneficiary.vault().assets().collect::<Vec<_>>(), vec![FungibleAsset::mock(100)]);
+}
+
+#[test]
+fn escrow_release_by_arbiter() {
+    let EscrowSetup {
+        mut mock_chain,
+        beneficiary,
+        arbiter,
+        note,
+        beneficiary_recipient,
+        ..
+    } = setup_escrow();
+
+    // the beneficiary can not release the note by itself
+    let execution_result = mock_chain
+        .build_tx_context(beneficiary.id(), &[note.id()], &[])
+        .note_args(escrow_note_args(&note, EscrowAction::ReleaseByArbiter))
+        .build()
+        .execute();
+
+    assert_transaction_executor_error!(execution_result, ERR_ESCROW_CONSUMER_IS_NOT_ARBITER);
+
+    // the arbiter forwards the assets to the beneficiary
+    let expected_note =
+        p2id_note(arbiter.id(), beneficiary.id(), beneficiary_recipient, FungibleAsset::mock(100));
+
+    let executed_transaction = mock_chain
+        .build_tx_context(arbiter.id(), &[note.id()], &[])
+        .note_args(escrow_note_args(&note, EscrowAction::ReleaseByArbiter))
+        .expected_notes(vec![OutputNote::Full(expected_note.clone())])
+        .build()
+        .execute()
+        .unwrap();
+
+    assert_eq!(executed_transaction.output_notes().num_notes(), 1);
+    assert_eq!(executed_transaction.output_notes().get_note(0).id(), expected_note.id());
+
+    let arbiter = mock_chain.apply_executed_transaction(&executed_transaction);
+    assert_eq!(arbiter.vault().assets().count(), 0);
+}
+
+#[test]
+fn escrow_split_by_arbiter() {
+    let EscrowSetup {
+        mut mock_chain,
+        sender,
+        beneficiary,
+        arbiter,
+        note,
+        beneficiary_recipient,
+        sender_recipient,
+        ..
+    } = setup_escrow();
+
+    // the split amount must be lower than the escrowed amount
+    let execution_result = mock_chain
+        .build_tx_context(arbiter.id(), &[note.id()], &[])
+        .note_args(escrow_note_args(&note, EscrowAction::Split { beneficiary_amount: 100 }))
+        .build()
+        .execute();
+
+    assert_transaction_executor_error!(execution_result, ERR_ESCROW_INVALID_SPLIT_AMOUNT);
+
+    // the arbiter splits the escrowed asset between the beneficiary and the sender
+    let faucet_id = FungibleAsset::mock(100).unwrap_fungible().faucet_id();
+    let beneficiary_note = p2id_note(
+        arbiter.id(),
+        beneficiary.id(),
+        beneficiary_recipient,
+        FungibleAsset::new(faucet_id, 30).unwrap().into(),
+    );
+    let sender_note = p2id_note(
+        arbiter.id(),
+        sender.id(),
+        sender_recipient,
+        FungibleAsset::new(faucet_id, 70).unwrap().into(),
+    );
+
+    let executed_transaction = mock_chain
+        .build_tx_context(arbiter.id(), &[note.id()], &[])
+        .note_args(escrow_note_args(&note, EscrowAction::Split { beneficiary_amount: 30 }))
+        .expected_notes(vec![
+            OutputNote::Full(beneficiary_note.clone()),
+            OutputNote::Full(sender_note.clone()),
+        ])
+        .build()
+        .execute()
+        .unwrap();
+
+    let output_notes = executed_transaction.output_notes();
+    assert_eq!(output_notes.num_notes(), 2);
+    assert_eq!(output_notes.get_note(0).id(), beneficiary_note.id());
+    assert_eq!(output_notes.get_note(1).id(), sender_note.id());
+}
+
+#[test]
+fn escrow_refund_after_refund_height() {
+    let EscrowSetup { mut mock_chain, sender, note, .. } = setup_escrow();
+
+    // the sender can not claim a refund before the refund height
+    let execution_result = mock_chain
+        .build_tx_context(sender.id(), &[note.id()], &[])
+        .note_args(escrow_note_args(&note, EscrowAction::Refund))
+        .build()
+        .execute();
+
+    assert_transaction_executor_error!(execution_result, ERR_ESCROW_REFUND_HEIGHT_NOT_REACHED);
+
+    mock_chain.seal_block(Some(10));
+
+    let executed_transaction = mock_chain
+        .build_tx_context(sender.id(), &[note.id()], &[])
+        .note_args(escrow_note_args(&note, EscrowAction::Refund))
+        .build()
+        .execute()
+        .unwrap();
+
+    let sender = mock_chain.apply_executed_transaction(&executed_transaction);
+    assert_eq!(sender.vault().assets().collect::<Vec<_>>(), vec![FungibleAsset::mock(100)]);
+}
@@ -1,15 +1,28 @@
 extern crate alloc;
 
 use miden_lib::{
-    errors::tx_kernel_errors::ERR_FUNGIBLE_ASSET_DISTRIBUTE_WOULD_CAUSE_MAX_SUPPLY_TO_BE_EXCEEDED,
+    account::faucets::BasicFungibleFaucet,
+    errors::tx_kernel_errors::{
+        ERR_FUNGIBLE_ASSET_DISTRIBUTE_WOULD_CAUSE_MAX_SUPPLY_TO_BE_EXCEEDED,
+        ERR_FUNGIBLE_FAUCET_DISTRIBUTE_WOULD_EXCEED_RECIPIENT_MINT_CAP,
+        ERR_FUNGIBLE_FAUCET_DISTRIBUTION_IS_PAUSED,
+        ERR_FUNGIBLE_FAUCET_MAX_SUPPLY_BELOW_TOTAL_ISSUANCE,
+        ERR_FUNGIBLE_FAUCET_MAX_SUPPLY_CANNOT_BE_INCREASED,
+        ERR_FUNGIBLE_FAUCET_RECIPIENT_IS_NOT_P2ID_FOR_CAPPED_ACCOUNT,
+    },
+    note::utils::build_p2id_recipient,
     transaction::TransactionKernel,
 };
 use miden_objects::{
-    asset::{Asset, FungibleAsset},
+    account::{AccountBuilder, AccountId, AccountType},
+    asset::{Asset, FungibleAsset, TokenSymbol},
     note::{NoteAssets, NoteExecutionHint, NoteId, NoteMetadata, NoteTag, NoteType},
-    testing::prepare_word,
+    testing::{
+        account_id::{ACCOUNT_ID_OFF_CHAIN_SENDER, ACCOUNT_ID_SENDER},
+        prepare_word,
+    },
     transaction::TransactionScript,
-    Felt,
+    Felt, Word,
 };
 use miden_tx::testing::{Auth, MockChain};
 
@@ -153,6 +166,247 @@ fn faucet_contract_mint_fungible_asset_fails_exceeds_max_supply() {
     );
 }
 
+// TESTS ISSUANCE POLICIES
+// ================================================================================================
+
+#[test]
+fn faucet_contract_pause_and_resume_distribution() {
+    let mut mock_chain = MockChain::new();
+    let faucet = mock_chain.add_existing_account_from_builder(
+        Auth::BasicAuth,
+        AccountBuilder::new([3; 32])
+            .account_type(AccountType::FungibleFaucet)
+            .with_component(
+                BasicFungibleFaucet::new(TokenSymbol::new("TST").unwrap(), 10, Felt::new(200))
+                    .unwrap()
+                    .with_paused(true),
+            ),
+    );
+
+    let recipient = [Felt::new(0), Felt::new(1), Felt::new(2), Felt::new(3)];
+    let target = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+
+    // distributing assets while the faucet is paused must fail
+    let tx = mock_chain
+        .build_tx_context(faucet.id(), &[], &[])
+        .tx_script(distribute_script(recipient, target, [Felt::new(0); 4], 100))
+        .build()
+        .execute();
+
+    assert_transaction_executor_error!(tx, ERR_FUNGIBLE_FAUCET_DISTRIBUTION_IS_PAUSED);
+
+    // resume the distribution of assets
+    let executed_transaction = mock_chain
+        .build_tx_context(faucet.id(), &[], &[])
+        .tx_script(BasicFungibleFaucet::set_paused_script(false).unwrap())
+        .build()
+        .execute()
+        .unwrap();
+
+    let faucet = mock_chain.apply_executed_transaction(&executed_transaction);
+    mock_chain.seal_block(None);
+
+    assert_eq!(
+        faucet.storage().get_item(1 + BasicFungibleFaucet::POLICY_SLOT).unwrap(),
+        Word::default().into()
+    );
+
+    let executed_transaction = mock_chain
+        .build_tx_context(faucet.id(), &[], &[])
+        .tx_script(distribute_script(recipient, target, [Felt::new(0); 4], 100))
+        .build()
+        .execute()
+        .unwrap();
+
+    assert_eq!(executed_transaction.output_notes().num_notes(), 1);
+}
+
+#[test]
+fn faucet_contract_distribute_respects_recipient_mint_cap() {
+    let mut mock_chain = MockChain::new();
+    let faucet = mock_chain.add_existing_account_from_builder(
+        Auth::BasicAuth,
+        AccountBuilder::new([4; 32])
+            .account_type(AccountType::FungibleFaucet)
+            .with_component(
+                BasicFungibleFaucet::new(TokenSymbol::new("TST").unwrap(), 10, Felt::new(1000))
+                    .unwrap()
+                    .with_recipient_mint_cap(Felt::new(150))
+                    .unwrap(),
+            ),
+    );
+
+    let target = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let other_target = AccountId::try_from(ACCOUNT_ID_OFF_CHAIN_SENDER).unwrap();
+    let serial_num = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+    let other_serial_num = [Felt::new(5), Felt::new(6), Felt::new(7), Felt::new(8)];
+
+    let executed_transaction = mock_chain
+        .build_tx_context(faucet.id(), &[], &[])
+        .tx_script(p2id_distribute_script(target, serial_num, 100))
+        .build()
+        .execute()
+        .unwrap();
+
+    let faucet = mock_chain.apply_executed_transaction(&executed_transaction);
+    mock_chain.seal_block(None);
+
+    // The faucet component is added first, so its storage slot offset is 1.
+    assert_eq!(
+        BasicFungibleFaucet::read_recipient_minted(faucet.storage(), 1, target).unwrap(),
+        Felt::new(100)
+    );
+
+    // distributing another 100 tokens to the same account would exceed the cap, even if the note
+    // has a different serial number
+    let tx = mock_chain
+        .build_tx_context(faucet.id(), &[], &[])
+        .tx_script(p2id_distribute_script(target, other_serial_num, 100))
+        .build()
+        .execute();
+
+    assert_transaction_executor_error!(
+        tx,
+        ERR_FUNGIBLE_FAUCET_DISTRIBUTE_WOULD_EXCEED_RECIPIENT_MINT_CAP
+    );
+
+    // the cap is tracked per account
+    let executed_transaction = mock_chain
+        .build_tx_context(faucet.id(), &[], &[])
+        .tx_script(p2id_distribute_script(other_target, serial_num, 100))
+        .build()
+        .execute()
+        .unwrap();
+
+    let faucet = mock_chain.apply_executed_transaction(&executed_transaction);
+    mock_chain.seal_block(None);
+
+    assert_eq!(
+        BasicFungibleFaucet::read_recipient_minted(faucet.storage(), 1, other_target).unwrap(),
+        Felt::new(100)
+    );
+
+    // removing the cap allows distributing more tokens to the first account
+    let executed_transaction = mock_chain
+        .build_tx_context(faucet.id(), &[], &[])
+        .tx_script(BasicFungibleFaucet::set_recipient_mint_cap_script(None).unwrap())
+        .build()
+        .execute()
+        .unwrap();
+
+    mock_chain.apply_executed_transaction(&executed_transaction);
+    mock_chain.seal_block(None);
+
+    mock_chain
+        .build_tx_context(faucet.id(), &[], &[])
+        .tx_script(p2id_distribute_script(target, other_serial_num, 100))
+        .build()
+        .execute()
+        .unwrap();
+}
+
+#[test]
+fn faucet_contract_recipient_mint_cap_requires_p2id_recipient() {
+    let mut mock_chain = MockChain::new();
+    let faucet = mock_chain.add_existing_account_from_builder(
+        Auth::BasicAuth,
+        AccountBuilder::new([4; 32])
+            .account_type(AccountType::FungibleFaucet)
+            .with_component(
+                BasicFungibleFaucet::new(TokenSymbol::new("TST").unwrap(), 10, Felt::new(1000))
+                    .unwrap()
+                    .with_recipient_mint_cap(Felt::new(150))
+                    .unwrap(),
+            ),
+    );
+
+    let target = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let other_target = AccountId::try_from(ACCOUNT_ID_OFF_CHAIN_SENDER).unwrap();
+    let serial_num = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+    let target_recipient: Word = build_p2id_recipient(target, serial_num).unwrap().digest().into();
+
+    // the note pays to `target`, but the amount would be counted against `other_target`
+    let tx = mock_chain
+        .build_tx_context(faucet.id(), &[], &[])
+        .tx_script(distribute_script(target_recipient, other_target, serial_num, 100))
+        .build()
+        .execute();
+
+    assert_transaction_executor_error!(
+        tx,
+        ERR_FUNGIBLE_FAUCET_RECIPIENT_IS_NOT_P2ID_FOR_CAPPED_ACCOUNT
+    );
+
+    // notes which are not P2ID notes cannot be distributed while a cap is set
+    let tx = mock_chain
+        .build_tx_context(faucet.id(), &[], &[])
+        .tx_script(distribute_script(
+            [Felt::new(0), Felt::new(1), Felt::new(2), Felt::new(3)],
+            target,
+            serial_num,
+            100,
+        ))
+        .build()
+        .execute();
+
+    assert_transaction_executor_error!(
+        tx,
+        ERR_FUNGIBLE_FAUCET_RECIPIENT_IS_NOT_P2ID_FOR_CAPPED_ACCOUNT
+    );
+}
+
+#[test]
+fn faucet_contract_set_max_supply() {
+    let mut mock_chain = MockChain::new();
+    let faucet = mock_chain.add_existing_faucet(Auth::BasicAuth, "TST", 200, Some(100));
+
+    // the max supply cannot be increased
+    let tx = mock_chain
+        .build_tx_context(faucet.id(), &[], &[])
+        .tx_script(BasicFungibleFaucet::set_max_supply_script(Felt::new(250)).unwrap())
+        .build()
+        .execute();
+
+    assert_transaction_executor_error!(tx, ERR_FUNGIBLE_FAUCET_MAX_SUPPLY_CANNOT_BE_INCREASED);
+
+    // the max supply cannot be lowered below the total issuance
+    let tx = mock_chain
+        .build_tx_context(faucet.id(), &[], &[])
+        .tx_script(BasicFungibleFaucet::set_max_supply_script(Felt::new(50)).unwrap())
+        .build()
+        .execute();
+
+    assert_transaction_executor_error!(tx, ERR_FUNGIBLE_FAUCET_MAX_SUPPLY_BELOW_TOTAL_ISSUANCE);
+
+    let executed_transaction = mock_chain
+        .build_tx_context(faucet.id(), &[], &[])
+        .tx_script(BasicFungibleFaucet::set_max_supply_script(Felt::new(150)).unwrap())
+        .build()
+        .execute()
+        .unwrap();
+
+    let faucet_account = mock_chain.apply_executed_transaction(&executed_transaction);
+    mock_chain.seal_block(None);
+
+    assert_eq!(faucet_account.storage().get_item(1).unwrap()[0], Felt::new(150));
+
+    // distributing more than the lowered max supply allows must fail
+    let tx = mock_chain
+        .build_tx_context(faucet.id(), &[], &[])
+        .tx_script(p2id_distribute_script(
+            AccountId::try_from(ACCOUNT_ID_SENDER).unwrap(),
+            [Felt::new(0); 4],
+            100,
+        ))
+        .build()
+        .execute();
+
+    assert_transaction_executor_error!(
+        tx,
+        ERR_FUNGIBLE_ASSET_DISTRIBUTE_WOULD_CAUSE_MAX_SUPPLY_TO_BE_EXCEEDED
+    );
+}
+
 // TESTS BURN FUNGIBLE ASSET
 // ================================================================================================
 
@@ -214,3 +468,62 @@ fn prove_faucet_contract_burn_fungible_asset_succeeds() {
     assert_eq!(executed_transaction.account_delta().nonce(), Some(Felt::new(3)));
     assert_eq!(executed_transaction.input_notes().get_note(0).id(), note.id());
 }
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the transaction script distributing the provided amount to the specified recipient in
+/// a private note intended for the `target` account.
+fn distribute_script(
+    recipient: Word,
+    target: AccountId,
+    serial_num: Word,
+    amount: u64,
+) -> TransactionScript {
+    let tx_script_code = format!(
+        "
+            begin
+                # pad the stack before call
+                push.0
+
+                push.{serial_num}
+                push.{target_suffix}
+                push.{target_prefix}
+                push.{recipient}
+                push.{note_execution_hint}
+                push.{note_type}
+                push.0
+                push.{tag}
+                push.{amount}
+                # => [amount, tag, aux, note_type, execution_hint, RECIPIENT, target_prefix,
+                #     target_suffix, SERIAL_NUM, pad(1)]
+
+                call.::miden::contracts::faucets::basic_fungible::distribute
+                # => [note_idx, pad(15)]
+
+                call.::miden::contracts::auth::basic::auth_tx_rpo_falcon512
+                # => [note_idx, pad(15)]
+
+                # truncate the stack
+                dropw dropw dropw dropw
+            end
+            ",
+        serial_num = prepare_word(&serial_num),
+        target_suffix = target.suffix(),
+        target_prefix = target.prefix().as_felt(),
+        recipient = prepare_word(&recipient),
+        note_execution_hint = Felt::from(NoteExecutionHint::always()),
+        note_type = NoteType::Private as u8,
+        tag = u32::from(NoteTag::for_local_use_case(0, 0).unwrap()),
+    );
+
+    TransactionScript::compile(tx_script_code, vec![], TransactionKernel::testing_assembler())
+        .unwrap()
+}
+
+/// Returns the transaction script distributing the provided amount in a private P2ID note for the
+/// `target` account.
+fn p2id_distribute_script(target: AccountId, serial_num: Word, amount: u64) -> TransactionScript {
+    let recipient = build_p2id_recipient(target, serial_num).unwrap();
+    distribute_script(recipient.digest().into(), target, serial_num, amount)
+}
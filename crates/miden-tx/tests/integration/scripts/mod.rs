@@ -1,4 +1,7 @@
+mod blob_storage;
+mod escrow;
 mod faucet;
 mod p2id;
 mod p2idr;
+mod price_oracle;
 mod swap;
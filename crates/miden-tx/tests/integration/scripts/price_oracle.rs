@@ -0,0 +1,158 @@
+use miden_lib::{
+    account::oracles::{PriceEntry, PriceOracle, PricePair},
+    errors::tx_kernel_errors::ERR_ORACLE_PRICE_ENTRY_IS_STALE,
+};
+use miden_objects::{
+    account::AccountBuilder,
+    asset::{FungibleAsset, TokenSymbol},
+    crypto::{dsa::rpo_falcon512::SecretKey, merkle::LeafIndex},
+    Felt,
+};
+use miden_tx::{
+    auth::signatures::get_falcon_signature,
+    testing::{Auth, MockChain},
+    TransactionExecutorError,
+};
+use rand_chacha::{rand_core::SeedableRng, ChaCha20Rng};
+
+use crate::{assert_transaction_executor_error, get_note_with_fungible_asset_and_script};
+
+#[test]
+fn price_oracle_push_price_and_read_it_via_fpi() {
+    let mut rng = ChaCha20Rng::from_seed([0; 32]);
+    let publisher_key = SecretKey::with_rng(&mut rng);
+
+    let btc_usd =
+        PricePair::new(TokenSymbol::new("BTC").unwrap(), TokenSymbol::new("USD").unwrap());
+    let entry = PriceEntry::new(Felt::new(95_000), 0, 1_000).unwrap();
+
+    // PUSH A SIGNED PRICE ENTRY
+    // --------------------------------------------------------------------------------------------
+    let oracle_component = PriceOracle::new(publisher_key.public_key());
+
+    let mut mock_chain = MockChain::new();
+    let oracle = mock_chain.add_existing_account_from_builder(
+        Auth::BasicAuth,
+        AccountBuilder::new([9; 32]).with_component(PriceOracle::new(publisher_key.public_key())),
+    );
+
+    let signature = get_falcon_signature(
+        &publisher_key,
+        entry.signing_message(oracle.id(), &btc_usd),
+        &mut rng,
+    )
+    .unwrap();
+    let push_price_script = oracle_component
+        .push_price_script(oracle.id(), &btc_usd, &entry, signature.clone())
+        .unwrap();
+
+    let executed_transaction = mock_chain
+        .build_tx_context(oracle.id(), &[], &[])
+        .tx_script(push_price_script.clone())
+        .build()
+        .execute()
+        .unwrap();
+
+    let oracle = mock_chain.apply_executed_transaction(&executed_transaction);
+    mock_chain.seal_block(None);
+
+    assert_eq!(PriceOracle::read_price(oracle.storage(), 0, &btc_usd).unwrap(), Some(entry));
+
+    // replaying the same signed entry must fail
+    let execution_result = mock_chain
+        .build_tx_context(oracle.id(), &[], &[])
+        .tx_script(push_price_script)
+        .build()
+        .execute();
+
+    assert_transaction_executor_error!(execution_result, ERR_ORACLE_PRICE_ENTRY_IS_STALE);
+
+    // the entry signed for the first oracle must not be accepted by another oracle trusting the
+    // same publisher
+    let other_oracle = mock_chain.add_existing_account_from_builder(
+        Auth::BasicAuth,
+        AccountBuilder::new([10; 32]).with_component(PriceOracle::new(publisher_key.public_key())),
+    );
+    let replay_script = oracle_component
+        .push_price_script(other_oracle.id(), &btc_usd, &entry, signature)
+        .unwrap();
+
+    let execution_result = mock_chain
+        .build_tx_context(other_oracle.id(), &[], &[])
+        .tx_script(replay_script)
+        .build()
+        .execute();
+
+    // the signature does not match the message bound to the other oracle, so the falcon verifier
+    // rejects it
+    assert!(matches!(
+        execution_result,
+        Err(TransactionExecutorError::TransactionProgramExecutionFailed(_))
+    ));
+
+    // the same entry signed for the other oracle by the same publisher is accepted, so the failure
+    // above is caused by the oracle ID bound into the signed message
+    let other_signature = get_falcon_signature(
+        &publisher_key,
+        entry.signing_message(other_oracle.id(), &btc_usd),
+        &mut rng,
+    )
+    .unwrap();
+    let other_push_price_script = oracle_component
+        .push_price_script(other_oracle.id(), &btc_usd, &entry, other_signature)
+        .unwrap();
+
+    mock_chain
+        .build_tx_context(other_oracle.id(), &[], &[])
+        .tx_script(other_push_price_script)
+        .build()
+        .execute()
+        .unwrap();
+
+    // CONSUME A NOTE READING THE PRICE VIA FPI
+    // --------------------------------------------------------------------------------------------
+    let consumer = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+
+    let note_script = format!(
+        "
+        use.miden::contracts::wallets::basic->wallet
+        use.miden::note
+
+        begin
+            # read the BTC/USD price from the oracle
+            {get_price}
+            # => [0, timestamp, decimals, price]
+
+            # only release the offered asset if the price is at least the minimum price
+            drop drop drop push.{min_price} gte assert
+            # => []
+
+            # add the offered asset to the consuming account
+            push.0 exec.note::get_assets drop
+            padw movup.4 mem_loadw
+            # => [ASSET]
+
+            padw swapw padw padw swapdw
+            call.wallet::receive_asset
+            dropw dropw dropw dropw
+        end
+        ",
+        get_price = PriceOracle::get_price_via_fpi_code(oracle.id(), &btc_usd),
+        min_price = 90_000,
+    );
+    let note = get_note_with_fungible_asset_and_script(
+        FungibleAsset::mock(100).unwrap_fungible(),
+        &note_script,
+    );
+
+    let account_path = mock_chain.accounts().open(&LeafIndex::from(oracle.id())).path;
+    let advice_inputs = PriceOracle::fpi_advice_inputs(&oracle, &account_path).unwrap();
+
+    mock_chain
+        .build_tx_context(consumer.id(), &[], &[note])
+        .foreign_account_codes(vec![oracle.code().clone()])
+        .advice_inputs(advice_inputs)
+        .build()
+        .execute()
+        .unwrap();
+}
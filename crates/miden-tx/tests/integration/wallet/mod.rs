@@ -53,3 +53,159 @@ fn wallet_creation() {
     let pub_key_word: Word = pub_key.into();
     assert_eq!(wallet.storage().get_item(0).unwrap().as_elements(), pub_key_word);
 }
+
+#[test]
+fn wallet_code_upgrade() {
+    use miden_lib::{
+        account::{auth::RpoFalcon512Upgradable, wallets::BasicWallet},
+        errors::tx_kernel_errors::ERR_ACCOUNT_CODE_IS_NOT_UPDATABLE,
+        transaction::TransactionKernel,
+    };
+    use miden_objects::{
+        account::{
+            delta::AccountUpdateDetails, Account, AccountCode, AccountComponent,
+            AccountStorageMode, AccountType,
+        },
+        testing::prepare_word,
+        transaction::TransactionScript,
+        AccountError,
+    };
+    use miden_prover::ProvingOptions;
+    use miden_tx::{
+        testing::{Auth, MockChain},
+        LocalTransactionProver, TransactionExecutorError, TransactionProver,
+    };
+
+    use crate::assert_transaction_executor_error;
+
+    let mut mock_chain = MockChain::new();
+    let wallet = mock_chain.add_existing_account_from_builder(
+        Auth::UpgradableAuth,
+        Account::builder([1; 32])
+            .storage_mode(AccountStorageMode::Public)
+            .with_component(BasicWallet),
+    );
+
+    // the new code extends the code of the wallet by a component with an additional procedure
+    let version_component = AccountComponent::compile(
+        "export.get_version push.2 swap drop end",
+        TransactionKernel::testing_assembler(),
+        vec![],
+    )
+    .unwrap()
+    .with_supports_all_types();
+    let pub_key = SecretKey::with_rng(&mut ChaCha20Rng::from_seed([0; 32])).public_key();
+    let new_code = AccountCode::from_components(
+        &[
+            BasicWallet.into(),
+            RpoFalcon512Upgradable::new(pub_key).into(),
+            version_component,
+        ],
+        AccountType::RegularAccountUpdatableCode,
+    )
+    .unwrap();
+
+    let tx_script_src = format!(
+        "
+        begin
+            push.{code_commitment}
+            call.::miden::contracts::auth::basic::upgrade_code_rpo_falcon512
+            dropw
+        end
+        ",
+        code_commitment = prepare_word(&new_code.commitment()),
+    );
+    let tx_script =
+        TransactionScript::compile(tx_script_src, vec![], TransactionKernel::testing_assembler())
+            .unwrap();
+
+    // the new code must be provided when upgrading the account code
+    let execution_result = mock_chain
+        .build_tx_context(wallet.id(), &[], &[])
+        .tx_script(tx_script.clone())
+        .build()
+        .execute();
+    assert!(matches!(
+        execution_result,
+        Err(TransactionExecutorError::MissingNewAccountCode(commitment))
+            if commitment == new_code.commitment()
+    ));
+
+    let executed_transaction = mock_chain
+        .build_tx_context(wallet.id(), &[], &[])
+        .tx_script(tx_script.clone())
+        .new_account_code(new_code.clone())
+        .build()
+        .execute()
+        .unwrap();
+    assert_eq!(executed_transaction.final_account().code_commitment(), new_code.commitment());
+
+    let proven_transaction = LocalTransactionProver::new(ProvingOptions::default())
+        .prove(executed_transaction.clone().into())
+        .unwrap();
+    let AccountUpdateDetails::CodeUpgrade(code, delta) =
+        proven_transaction.account_update().details()
+    else {
+        panic!("expected code upgrade account update details");
+    };
+    assert_eq!(code, &new_code);
+
+    // applying the update yields the final state of the account
+    let mut upgraded_wallet = wallet.clone();
+    upgraded_wallet.apply_delta(delta).unwrap();
+    upgraded_wallet.upgrade_code(code.clone()).unwrap();
+    assert_eq!(upgraded_wallet.hash(), proven_transaction.account_update().final_state_hash());
+
+    let upgraded_wallet = mock_chain.apply_executed_transaction(&executed_transaction);
+    assert_eq!(upgraded_wallet.code(), &new_code);
+
+    // the upgradable auth component cannot be used by accounts with immutable code
+    let build_result = Account::builder([2; 32])
+        .account_type(AccountType::RegularAccountImmutableCode)
+        .with_component(BasicWallet)
+        .with_component(RpoFalcon512Upgradable::new(pub_key))
+        .build_existing();
+    let Err(AccountError::BuildError(_, Some(source))) = build_result else {
+        panic!("expected account build error");
+    };
+    assert!(matches!(*source, AccountError::UnsupportedComponentForAccountType { .. }));
+
+    // the kernel rejects setting the code of accounts with immutable code
+    let set_code_library = TransactionKernel::testing_assembler()
+        .assemble_library(["use.miden::account export.set_code exec.account::set_code end"])
+        .unwrap();
+    let set_code_export = set_code_library.exports().next().unwrap();
+    let set_code_root = set_code_library.mast_forest()
+        [set_code_library.get_export_node_id(set_code_export)]
+    .digest();
+    let set_code_component = AccountComponent::new(set_code_library, vec![])
+        .unwrap()
+        .with_supports_all_types();
+    let immutable_wallet = mock_chain.add_existing_account_from_builder(
+        Auth::BasicAuth,
+        Account::builder([2; 32])
+            .account_type(AccountType::RegularAccountImmutableCode)
+            .with_component(BasicWallet)
+            .with_component(set_code_component),
+    );
+    let tx_script_src = format!(
+        "
+        begin
+            push.{code_commitment}
+            call.{set_code_root}
+            dropw
+        end
+        ",
+        code_commitment = prepare_word(&new_code.commitment()),
+    );
+    let tx_script =
+        TransactionScript::compile(tx_script_src, vec![], TransactionKernel::testing_assembler())
+            .unwrap();
+    let execution_result = mock_chain
+        .build_tx_context(immutable_wallet.id(), &[], &[])
+        .tx_script(tx_script)
+        .new_account_code(new_code)
+        .build()
+        .execute();
+    assert_transaction_executor_error!(execution_result, ERR_ACCOUNT_CODE_IS_NOT_UPDATABLE);
+}